@@ -14,6 +14,11 @@ const IGNORED_ATTRS = [
   "entity_category",
 ];
 
+// Components whose discovery name differs from the name of the entity.
+const COMPONENT_NAMES: {[entityName: string]: string} = {
+  device_trigger: "device_automation",
+};
+
 type FieldAttributes = {
   description: string;
   required: boolean;
@@ -43,6 +48,7 @@ type MqttEntity = {
   entityDoc: string;
   imports: Set<string>;
   properties: object;
+  customDefault: boolean;
 };
 
 export function generateMqttEntityModel(
@@ -58,11 +64,21 @@ export function generateMqttEntityModel(
     const modelDescriptor = YAML.parse(modelDescriptorYaml!![1]);
     const entries = Object.entries(modelDescriptor)
       .filter(([name, attrs]) => !IGNORED_ATTRS.includes(name));
+    const componentName = COMPONENT_NAMES[entityName] ?? entityName;
+    // every component needs its platform in device discovery payloads, even when its documentation omits it
+    if (!entries.some(([name]) => name === "platform")) {
+      const index = entries.findIndex(([name]) => name > "platform");
+      entries.splice(index === -1 ? entries.length : index, 0, ["platform", {
+        description: `Must be \`${componentName}\`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).`,
+        required: true,
+        type: "string",
+      }]);
+    }
     for (const [name, attrs] of entries) {
       const attrsFieldAttributes = attrs as FieldAttributes
-      appendRustType(name, attrsFieldAttributes);
+      appendRustType(entityName, name, attrsFieldAttributes);
       if (name === "platform") {
-        attrsFieldAttributes.defaultValue = entityName;
+        attrsFieldAttributes.defaultValue = componentName;
       }
     }
 
@@ -71,6 +87,7 @@ export function generateMqttEntityModel(
       entityDoc: "", //docContent,
       imports: new Set(entries.map(([name, attrs]) => attrs.import).filter(importInstruction => !!importInstruction)),
       properties: Object.fromEntries(entries),
+      customDefault: entries.some(([name, attrs]) => !!(attrs as FieldAttributes).defaultValue),
     };
   } catch (e) {
     console.error(modelDescriptorYaml!![1]);
@@ -78,7 +95,23 @@ export function generateMqttEntityModel(
   }
 }
 
-function appendRustType(name: string, attrs: FieldAttributes) {
+/** Lists typed with the well-known values of Home Assistant, by entity and attribute: the module and the type. */
+const TYPED_LISTS: {[entityName: string]: {[name: string]: [string, string]}} = {
+  alarm_control_panel: {
+    supported_features: ["features", "AlarmFeature"],
+  },
+  climate: {
+    modes: ["climate_modes", "HvacMode"],
+    fan_modes: ["climate_modes", "FanMode"],
+    swing_modes: ["climate_modes", "SwingMode"],
+    preset_modes: ["climate_modes", "PresetMode"],
+  },
+  vacuum: {
+    supported_features: ["features", "VacuumFeature"],
+  },
+};
+
+function appendRustType(entityName: string, name: string, attrs: FieldAttributes) {
   if (name === "type") {
     attrs.rustSafeName = `r#${name}`;
   } else {
@@ -143,4 +176,10 @@ function appendRustType(name: string, attrs: FieldAttributes) {
       attrs.import = `use super::common::TemperatureUnit`;
       break;
   }
+  const typedList = TYPED_LISTS[entityName]?.[name];
+  if (typedList) {
+    const [module, rustType] = typedList;
+    attrs.rustType = rustType;
+    attrs.import = `use super::${module}::${rustType}`;
+  }
 }
@@ -1,6 +1,11 @@
 import { readFileSync, writeFileSync, readdirSync } from "fs";
 import Handlebars from "handlebars";
-import { allAbbreviations } from "./abbretiations";
+import {
+  abbreviations,
+  allAbbreviations,
+  device,
+  origin,
+} from "./abbretiations";
 import { extractDeviceClassesEnums } from "./device-class";
 import { generateMqttEntityModel } from "./entity";
 import { toPascalCase } from "./strings";
@@ -21,8 +26,8 @@ const ENTITIES = [
   "humidifier",
   "image",
   "lawn_mower",
-  //"light",
   "lock",
+  "notify",
   "number",
   "scene",
   "select",
@@ -37,6 +42,10 @@ const ENTITIES = [
   "water_heater",
 ];
 
+// modules written by hand, as their documentation holds many configurations
+// (light has one configuration per schema)
+const HANDWRITTEN_ENTITIES = ["light"];
+
 Handlebars.registerHelper("abbreviation", (name: string) => {
   const abbreviation = Object.entries(allAbbreviations).find(
     ([shortName, fullName]) => name === fullName
@@ -44,6 +53,17 @@ Handlebars.registerHelper("abbreviation", (name: string) => {
   return new Handlebars.SafeString(abbreviation ? abbreviation[0] : name);
 });
 
+// the full name is accepted as well when deserializing
+Handlebars.registerHelper("alias", (name: string) => {
+  if (name === "platform") {
+    return ', alias = "p"';
+  }
+  const abbreviation = Object.entries(allAbbreviations).find(
+    ([shortName, fullName]) => name === fullName
+  );
+  return abbreviation && abbreviation[0] !== name ? `, alias = "${name}"` : "";
+});
+
 Handlebars.registerHelper("comment", (text: string) => {
   return text?.replaceAll("\n", "\n/// ");
 });
@@ -79,9 +99,22 @@ const template = readFileSync(
 const output = Handlebars.compile(template)(enumsModels);
 writeFileSync(`${BASEDIR}/src/mqtt/device_classes.rs`, output);
 
+// generate abbreviations tables
+const templateAbbreviations = readFileSync(
+  `${BASEDIR}/generator/src/rust_abbreviations.mustache`
+).toString();
+const outputAbbreviations = Handlebars.compile(templateAbbreviations)({
+  abbreviations,
+  device,
+  origin,
+});
+writeFileSync(`${BASEDIR}/src/mqtt/abbreviations.rs`, outputAbbreviations);
+
 // generate mod.rs
 const templateMod = readFileSync(
   `${BASEDIR}/generator/src/rust_mod.mustache`
 ).toString();
-const outputMod = Handlebars.compile(templateMod)(ENTITIES);
+const outputMod = Handlebars.compile(templateMod)(
+  [...ENTITIES, ...HANDWRITTEN_ENTITIES].sort()
+);
 writeFileSync(`${BASEDIR}/src/mqtt/mod.rs`, outputMod);
\ No newline at end of file
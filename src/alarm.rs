@@ -0,0 +1,336 @@
+//! State machine of an alarm control panel implemented by the device,
+//! applying the commands received from Home Assistant.
+
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::command::{AlarmAction, AlarmCommand};
+use crate::mqtt::alarm_control_panel::AlarmControlPanel;
+use crate::mqtt::features::AlarmFeature;
+use crate::mqtt::string_enum::string_enum;
+use crate::publisher::PublishError;
+use crate::state::StatePublisher;
+use crate::transport::DiscoveryTransport;
+
+string_enum! {
+    /// States of an alarm control panel, published on `state_topic`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum AlarmState {
+        #[serde(rename = "disarmed")]
+        Disarmed,
+        #[serde(rename = "armed_home")]
+        ArmedHome,
+        #[serde(rename = "armed_away")]
+        ArmedAway,
+        #[serde(rename = "armed_night")]
+        ArmedNight,
+        #[serde(rename = "armed_vacation")]
+        ArmedVacation,
+        #[serde(rename = "armed_custom_bypass")]
+        ArmedCustomBypass,
+        /// The alarm has been triggered and is waiting for the delay before going off.
+        #[serde(rename = "pending")]
+        Pending,
+        #[serde(rename = "triggered")]
+        Triggered,
+        /// The alarm is waiting for the arming time before being armed.
+        #[serde(rename = "arming")]
+        Arming,
+        #[serde(rename = "disarming")]
+        Disarming,
+    }
+}
+
+/// Errors raised when a command can't be applied to an alarm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlarmError {
+    /// The code is required by the action but is missing or wrong.
+    InvalidCode(AlarmAction),
+    /// The action isn't one of the `supported_features` of the alarm.
+    Unsupported(AlarmAction),
+}
+
+impl Display for AlarmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlarmError::InvalidCode(action) => write!(f, "invalid code for action {action:?}"),
+            AlarmError::Unsupported(action) => write!(f, "unsupported action {action:?}"),
+        }
+    }
+}
+
+impl std::error::Error for AlarmError {}
+
+/// The feature of an action and the state it leads to, none for disarm.
+fn armed_state(action: AlarmAction) -> Option<(AlarmFeature, AlarmState)> {
+    match action {
+        AlarmAction::ArmHome => Some((AlarmFeature::ArmHome, AlarmState::ArmedHome)),
+        AlarmAction::ArmAway => Some((AlarmFeature::ArmAway, AlarmState::ArmedAway)),
+        AlarmAction::ArmNight => Some((AlarmFeature::ArmNight, AlarmState::ArmedNight)),
+        AlarmAction::ArmVacation => Some((AlarmFeature::ArmVacation, AlarmState::ArmedVacation)),
+        AlarmAction::ArmCustomBypass => {
+            Some((AlarmFeature::ArmCustomBypass, AlarmState::ArmedCustomBypass))
+        }
+        AlarmAction::Trigger => Some((AlarmFeature::Trigger, AlarmState::Triggered)),
+        AlarmAction::Disarm => None,
+    }
+}
+
+/// An alarm control panel applying the commands of Home Assistant, with its arming and pending delays.
+///
+/// The alarm doesn't keep time itself: the caller passes the current instant to
+/// [`AlarmPanel::apply`] and calls [`AlarmPanel::update`] when [`AlarmPanel::deadline`] is reached.
+#[derive(Clone, Debug)]
+pub struct AlarmPanel {
+    entity: AlarmControlPanel,
+    code: Option<String>,
+    arming_time: Duration,
+    delay_time: Duration,
+    trigger_time: Option<Duration>,
+    state: AlarmState,
+    /// The disarmed or armed state the alarm returns to once the trigger time is over.
+    settled: AlarmState,
+    /// The state the alarm moves to at the deadline.
+    next: Option<(Instant, AlarmState)>,
+}
+
+impl AlarmPanel {
+    /// A disarmed alarm, with no delays.
+    pub fn new(entity: AlarmControlPanel) -> Self {
+        Self {
+            entity,
+            code: None,
+            arming_time: Duration::ZERO,
+            delay_time: Duration::ZERO,
+            trigger_time: None,
+            state: AlarmState::Disarmed,
+            settled: AlarmState::Disarmed,
+            next: None,
+        }
+    }
+
+    /// The code verified by the device. It is sent by Home Assistant in the commands when the
+    /// `code` of the entity is `REMOTE_CODE` or `REMOTE_CODE_TEXT`, and the `command_template` includes it.
+    /// The `code_arm_required`, `code_disarm_required` and `code_trigger_required` flags (default: true)
+    /// select the actions requiring it.
+    pub fn code<S: Into<String>>(mut self, code: S) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// The time spent arming before the alarm is armed. (default: 0)
+    pub fn arming_time(mut self, arming_time: Duration) -> Self {
+        self.arming_time = arming_time;
+        self
+    }
+
+    /// The time spent pending once triggered while armed, before the alarm goes off. (default: 0)
+    pub fn delay_time(mut self, delay_time: Duration) -> Self {
+        self.delay_time = delay_time;
+        self
+    }
+
+    /// The time the alarm stays triggered before returning to its previous state. (default: until disarmed)
+    pub fn trigger_time(mut self, trigger_time: Duration) -> Self {
+        self.trigger_time = Some(trigger_time);
+        self
+    }
+
+    /// The configuration of the alarm.
+    pub fn entity(&self) -> &AlarmControlPanel {
+        &self.entity
+    }
+
+    /// The current state of the alarm.
+    pub fn state(&self) -> AlarmState {
+        self.state
+    }
+
+    /// The instant the alarm leaves its arming, pending or triggered state, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.next.map(|(deadline, _)| deadline)
+    }
+
+    /// Applies a command received at `now`, and returns whether the state changed.
+    pub fn apply(&mut self, command: &AlarmCommand, now: Instant) -> Result<bool, AlarmError> {
+        let action = command.action;
+        let Some((feature, armed)) = armed_state(action) else {
+            self.verify_code(self.entity.code_disarm_required, command)?;
+            self.settled = AlarmState::Disarmed;
+            return Ok(self.enter(AlarmState::Disarmed, None));
+        };
+        // every feature is supported by default
+        if let Some(features) = &self.entity.supported_features {
+            if !features.contains(&feature) {
+                return Err(AlarmError::Unsupported(action));
+            }
+        }
+        if action == AlarmAction::Trigger {
+            self.verify_code(self.entity.code_trigger_required, command)?;
+            return Ok(match self.state {
+                AlarmState::Pending | AlarmState::Triggered => false,
+                // the entry delay only applies to an armed alarm
+                AlarmState::Disarmed | AlarmState::Arming | AlarmState::Disarming => {
+                    self.trigger(now)
+                }
+                _ if self.delay_time.is_zero() => self.trigger(now),
+                _ => self.enter(
+                    AlarmState::Pending,
+                    Some((now + self.delay_time, AlarmState::Triggered)),
+                ),
+            });
+        }
+        self.verify_code(self.entity.code_arm_required, command)?;
+        let arming = self.state == AlarmState::Arming;
+        if self.state == armed || arming && self.next.is_some_and(|(_, next)| next == armed) {
+            return Ok(false);
+        }
+        if self.arming_time.is_zero() {
+            self.settled = armed;
+            Ok(self.enter(armed, None))
+        } else {
+            self.settled = AlarmState::Disarmed;
+            Ok(self.enter(AlarmState::Arming, Some((now + self.arming_time, armed))))
+        }
+    }
+
+    /// Moves to the next state when the deadline is reached at `now`, and returns whether the state changed.
+    pub fn update(&mut self, now: Instant) -> bool {
+        match self.next {
+            Some((deadline, AlarmState::Triggered)) if deadline <= now => self.trigger(deadline),
+            Some((deadline, next)) if deadline <= now => {
+                if self.state == AlarmState::Arming {
+                    self.settled = next;
+                }
+                self.enter(next, None)
+            }
+            _ => false,
+        }
+    }
+
+    /// Publishes the current state of the alarm on its `state_topic`.
+    pub async fn publish_state<T: DiscoveryTransport>(
+        &self,
+        publisher: &StatePublisher<T>,
+    ) -> Result<(), PublishError<T::Error>> {
+        publisher.publish_state(&self.entity, &self.state).await
+    }
+
+    fn verify_code(
+        &self,
+        required: Option<bool>,
+        command: &AlarmCommand,
+    ) -> Result<(), AlarmError> {
+        match &self.code {
+            Some(code) if required.unwrap_or(true) && command.code.as_ref() != Some(code) => {
+                Err(AlarmError::InvalidCode(command.action))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn trigger(&mut self, now: Instant) -> bool {
+        let next = self
+            .trigger_time
+            .map(|trigger_time| (now + trigger_time, self.settled));
+        self.enter(AlarmState::Triggered, next)
+    }
+
+    fn enter(&mut self, state: AlarmState, next: Option<(Instant, AlarmState)>) -> bool {
+        self.next = next;
+        let changed = self.state != state;
+        self.state = state;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mqtt::common::Qos;
+    use crate::transport::testing::{block_on, RecordingTransport};
+    use crate::transport::Message;
+
+    fn command(action: AlarmAction, code: Option<&str>) -> AlarmCommand {
+        AlarmCommand {
+            action,
+            code: code.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn can_arm_after_the_arming_time_and_trigger_after_the_delay() {
+        let start = Instant::now();
+        let seconds = |seconds| start + Duration::from_secs(seconds);
+        let mut alarm = AlarmPanel::new(AlarmControlPanel::default())
+            .arming_time(Duration::from_secs(30))
+            .delay_time(Duration::from_secs(10))
+            .trigger_time(Duration::from_secs(60));
+
+        let arm_away = command(AlarmAction::ArmAway, None);
+        assert_eq!(alarm.apply(&arm_away, start), Ok(true));
+        assert_eq!(alarm.state(), AlarmState::Arming);
+        assert!(!alarm.update(seconds(29)));
+        assert!(alarm.update(seconds(30)));
+        assert_eq!(alarm.state(), AlarmState::ArmedAway);
+
+        let trigger = command(AlarmAction::Trigger, None);
+        assert_eq!(alarm.apply(&trigger, seconds(40)), Ok(true));
+        assert_eq!(alarm.state(), AlarmState::Pending);
+        assert_eq!(alarm.deadline(), Some(seconds(50)));
+        assert!(alarm.update(seconds(55)));
+        assert_eq!(alarm.state(), AlarmState::Triggered);
+        assert!(alarm.update(seconds(110)));
+        assert_eq!(alarm.state(), AlarmState::ArmedAway);
+    }
+
+    #[test]
+    fn cannot_apply_a_command_without_its_required_code() {
+        let now = Instant::now();
+        let panel = AlarmControlPanel::default()
+            .code("REMOTE_CODE")
+            .code_arm_required(false)
+            .supported_features(vec![AlarmFeature::ArmHome]);
+        let mut alarm = AlarmPanel::new(panel).code("1234");
+
+        assert_eq!(
+            alarm.apply(&command(AlarmAction::ArmHome, None), now),
+            Ok(true)
+        );
+        assert_eq!(
+            alarm.apply(&command(AlarmAction::ArmAway, None), now),
+            Err(AlarmError::Unsupported(AlarmAction::ArmAway))
+        );
+        assert_eq!(
+            alarm.apply(&command(AlarmAction::Disarm, Some("0000")), now),
+            Err(AlarmError::InvalidCode(AlarmAction::Disarm))
+        );
+        assert_eq!(
+            alarm.apply(&command(AlarmAction::Disarm, Some("1234")), now),
+            Ok(true)
+        );
+        assert_eq!(alarm.state(), AlarmState::Disarmed);
+    }
+
+    #[test]
+    fn can_publish_the_state_of_the_alarm() {
+        let transport = RecordingTransport::default();
+        let publisher = StatePublisher::new(transport.clone());
+        let mut alarm = AlarmPanel::new(
+            AlarmControlPanel::default()
+                .state_topic("alarm/state")
+                .retain(true),
+        );
+        alarm
+            .apply(&command(AlarmAction::ArmNight, None), Instant::now())
+            .unwrap();
+        block_on(alarm.publish_state(&publisher)).unwrap();
+
+        assert_eq!(
+            transport.messages.lock().unwrap()[0],
+            Message::new("alarm/state", "armed_night", true).qos(Qos::AtMostOnce)
+        );
+    }
+}
@@ -0,0 +1,1411 @@
+//! Decoding of the commands Home Assistant publishes on the command topics of entities.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde_json::Value;
+
+use crate::discovery::resolve_topic;
+use crate::mqtt::climate_modes::{FanMode, HvacMode, PresetMode, SwingMode};
+use crate::mqtt::{
+    alarm_control_panel::AlarmControlPanel, button::Button, climate::Climate, common::Qos,
+    cover::Cover, fan::Fan, lawn_mower::LawnMower, lock::Lock, number::Number, select::Select,
+    siren::Siren, switch::Switch, text::Text, vacuum::Vacuum, valve::Valve,
+};
+use crate::transport::DiscoveryTransport;
+use crate::Entity;
+
+/// Errors raised while decoding a command received on a command topic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommandError {
+    /// The payload isn't valid UTF-8.
+    InvalidUtf8,
+    /// The payload isn't one of the payloads configured for the topic.
+    UnknownPayload(String),
+    /// The payload isn't a valid value for the topic, such as a number out of range.
+    InvalidValue(String),
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::InvalidUtf8 => write!(f, "command payload should be valid UTF-8"),
+            CommandError::UnknownPayload(payload) => {
+                write!(f, "unknown command payload '{payload}'")
+            }
+            CommandError::InvalidValue(payload) => {
+                write!(f, "invalid command value '{payload}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// A message received on a topic, decoded against the command topics of an entity.
+struct Received<'a> {
+    topic_prefix: Option<&'a str>,
+    topic: &'a str,
+    payload: &'a [u8],
+}
+
+impl<'a> Received<'a> {
+    fn new(topic_prefix: &'a Option<String>, topic: &'a str, payload: &'a [u8]) -> Self {
+        Self {
+            topic_prefix: topic_prefix.as_deref(),
+            topic,
+            payload,
+        }
+    }
+
+    /// Whether the message has been received on the command topic, `~` being resolved.
+    fn is_on(&self, command_topic: Option<&str>) -> bool {
+        command_topic.is_some_and(|command_topic| {
+            resolve_topic(self.topic_prefix, command_topic) == self.topic
+        })
+    }
+
+    fn text(&self) -> Result<&'a str, CommandError> {
+        std::str::from_utf8(self.payload).map_err(|_| CommandError::InvalidUtf8)
+    }
+
+    /// The command whose configured payload is the received one.
+    fn choose<C, const N: usize>(&self, choices: [(&str, C); N]) -> Result<C, CommandError> {
+        let text = self.text()?;
+        choices
+            .into_iter()
+            .find(|(payload, _)| *payload == text)
+            .map(|(_, command)| command)
+            .ok_or_else(|| CommandError::UnknownPayload(text.to_string()))
+    }
+
+    fn decimal(&self) -> Result<Decimal, CommandError> {
+        let text = self.text()?;
+        Decimal::from_str(text.trim()).map_err(|_| CommandError::InvalidValue(text.to_string()))
+    }
+
+    /// The percentage of a value published between the closed (0%) and open (100%) values.
+    fn percentage(&self, closed: i32, open: i32) -> Result<u8, CommandError> {
+        let value = self.decimal()?;
+        let invalid = || CommandError::InvalidValue(value.to_string());
+        let range = Decimal::from(i64::from(open) - i64::from(closed));
+        if range.is_zero() {
+            return Err(invalid());
+        }
+        let percentage = ((value - Decimal::from(closed)) * Decimal::ONE_HUNDRED / range).round();
+        percentage
+            .to_u8()
+            .filter(|percentage| *percentage <= 100)
+            .ok_or_else(invalid)
+    }
+}
+
+/// The command topics of an entity, `~` being resolved.
+fn resolved<const N: usize>(
+    topic_prefix: &Option<String>,
+    topics: [Option<&str>; N],
+) -> Vec<String> {
+    topics
+        .into_iter()
+        .flatten()
+        .map(|topic| resolve_topic(topic_prefix.as_deref(), topic))
+        .collect()
+}
+
+/// An entity receiving commands from Home Assistant on the command topics of its configuration.
+///
+/// Payloads are decoded as Home Assistant publishes them without a `command_template`. Only the command
+/// templates of lawn mowers are rendered to recognize their payloads, with the `templates` feature.
+/// The templates of the other entities are ignored: a templated payload is only decoded when it has
+/// the shape documented by the command, such as the JSON object of an [`AlarmCommand`].
+pub trait EntityCommands {
+    /// The typed commands of the entity.
+    type Command;
+
+    /// The command topics of the entity, `~` being resolved.
+    fn command_topics(&self) -> Vec<String>;
+
+    /// Decodes a message with the payloads of the entity configuration,
+    /// or returns `None` when the topic isn't one of the command topics of the entity.
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<Self::Command, CommandError>>;
+}
+
+/// Actions of an alarm control panel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlarmAction {
+    /// `payload_arm_home` (default: `ARM_HOME`).
+    ArmHome,
+    /// `payload_arm_away` (default: `ARM_AWAY`).
+    ArmAway,
+    /// `payload_arm_night` (default: `ARM_NIGHT`).
+    ArmNight,
+    /// `payload_arm_vacation` (default: `ARM_VACATION`).
+    ArmVacation,
+    /// `payload_arm_custom_bypass` (default: `ARM_CUSTOM_BYPASS`).
+    ArmCustomBypass,
+    /// `payload_disarm` (default: `DISARM`).
+    Disarm,
+    /// `payload_trigger` (default: `TRIGGER`).
+    Trigger,
+}
+
+/// Command of an alarm control panel, received on `command_topic`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlarmCommand {
+    pub action: AlarmAction,
+    /// The code entered by the user, sent by a `command_template` such as
+    /// `{"action": "{{ action }}", "code": "{{ code }}"}`.
+    pub code: Option<String>,
+}
+
+impl EntityCommands for AlarmControlPanel {
+    type Command = AlarmCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [Some(&self.command_topic)])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<AlarmCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received.is_on(Some(&self.command_topic)).then(|| {
+            let text = received.text()?;
+            // the action is either the whole payload or the `action` of a JSON object
+            let (action, code) = match serde_json::from_str::<Value>(text) {
+                Ok(Value::Object(command)) => (
+                    command
+                        .get("action")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                    command
+                        .get("code")
+                        .and_then(Value::as_str)
+                        .filter(|code| !code.is_empty())
+                        .map(str::to_string),
+                ),
+                _ => (text.to_string(), None),
+            };
+            let action = Received::new(&self.topic_prefix, topic, action.as_bytes()).choose([
+                (
+                    self.payload_arm_home.as_deref().unwrap_or("ARM_HOME"),
+                    AlarmAction::ArmHome,
+                ),
+                (
+                    self.payload_arm_away.as_deref().unwrap_or("ARM_AWAY"),
+                    AlarmAction::ArmAway,
+                ),
+                (
+                    self.payload_arm_night.as_deref().unwrap_or("ARM_NIGHT"),
+                    AlarmAction::ArmNight,
+                ),
+                (
+                    self.payload_arm_vacation
+                        .as_deref()
+                        .unwrap_or("ARM_VACATION"),
+                    AlarmAction::ArmVacation,
+                ),
+                (
+                    self.payload_arm_custom_bypass
+                        .as_deref()
+                        .unwrap_or("ARM_CUSTOM_BYPASS"),
+                    AlarmAction::ArmCustomBypass,
+                ),
+                (
+                    self.payload_disarm.as_deref().unwrap_or("DISARM"),
+                    AlarmAction::Disarm,
+                ),
+                (
+                    self.payload_trigger.as_deref().unwrap_or("TRIGGER"),
+                    AlarmAction::Trigger,
+                ),
+            ])?;
+            Ok(AlarmCommand { action, code })
+        })
+    }
+}
+
+/// Commands of a switch, received on `command_topic`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SwitchCommand {
+    /// `payload_on` (default: `ON`).
+    On,
+    /// `payload_off` (default: `OFF`).
+    Off,
+}
+
+impl EntityCommands for Switch {
+    type Command = SwitchCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [Some(&self.command_topic)])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<SwitchCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received.is_on(Some(&self.command_topic)).then(|| {
+            received.choose([
+                (
+                    self.payload_on.as_deref().unwrap_or("ON"),
+                    SwitchCommand::On,
+                ),
+                (
+                    self.payload_off.as_deref().unwrap_or("OFF"),
+                    SwitchCommand::Off,
+                ),
+            ])
+        })
+    }
+}
+
+/// Commands of a lock, received on `command_topic`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LockCommand {
+    /// `payload_lock` (default: `LOCK`).
+    Lock,
+    /// `payload_unlock` (default: `UNLOCK`).
+    Unlock,
+    /// `payload_open` (default: `OPEN`).
+    Open,
+}
+
+impl EntityCommands for Lock {
+    type Command = LockCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [Some(&self.command_topic)])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<LockCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received.is_on(Some(&self.command_topic)).then(|| {
+            received.choose([
+                (
+                    self.payload_lock.as_deref().unwrap_or("LOCK"),
+                    LockCommand::Lock,
+                ),
+                (
+                    self.payload_unlock.as_deref().unwrap_or("UNLOCK"),
+                    LockCommand::Unlock,
+                ),
+                (
+                    self.payload_open.as_deref().unwrap_or("OPEN"),
+                    LockCommand::Open,
+                ),
+            ])
+        })
+    }
+}
+
+/// Commands of a cover.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CoverCommand {
+    /// `payload_open` (default: `OPEN`) on `command_topic`.
+    Open,
+    /// `payload_close` (default: `CLOSE`) on `command_topic`.
+    Close,
+    /// `payload_stop` (default: `STOP`) on `command_topic`.
+    Stop,
+    /// Position in percent, from closed (0) to open (100), received on `set_position_topic`
+    /// scaled between `position_closed` (default: 0) and `position_open` (default: 100).
+    SetPosition(u8),
+    /// Tilt in percent, from closed (0) to open (100), received on `tilt_command_topic`
+    /// scaled between `tilt_min` (default: 0) and `tilt_max` (default: 100).
+    SetTilt(u8),
+}
+
+impl EntityCommands for Cover {
+    type Command = CoverCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(
+            &self.topic_prefix,
+            [
+                self.command_topic.as_deref(),
+                self.set_position_topic.as_deref(),
+                self.tilt_command_topic.as_deref(),
+            ],
+        )
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<CoverCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        if received.is_on(self.command_topic.as_deref()) {
+            Some(received.choose([
+                (
+                    self.payload_open.as_deref().unwrap_or("OPEN"),
+                    CoverCommand::Open,
+                ),
+                (
+                    self.payload_close.as_deref().unwrap_or("CLOSE"),
+                    CoverCommand::Close,
+                ),
+                (
+                    self.payload_stop.as_deref().unwrap_or("STOP"),
+                    CoverCommand::Stop,
+                ),
+            ]))
+        } else if received.is_on(self.set_position_topic.as_deref()) {
+            Some(
+                received
+                    .percentage(
+                        self.position_closed.unwrap_or(0),
+                        self.position_open.unwrap_or(100),
+                    )
+                    .map(CoverCommand::SetPosition),
+            )
+        } else if received.is_on(self.tilt_command_topic.as_deref()) {
+            Some(
+                received
+                    .percentage(self.tilt_min.unwrap_or(0), self.tilt_max.unwrap_or(100))
+                    .map(CoverCommand::SetTilt),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+/// Commands of a fan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FanCommand {
+    /// `payload_on` (default: `ON`) on `command_topic`.
+    On,
+    /// `payload_off` (default: `OFF`) on `command_topic`.
+    Off,
+    /// Speed in percent received on `percentage_command_topic`,
+    /// scaled between `speed_range_min` (default: 1) and `speed_range_max` (default: 100).
+    SetPercentage(u8),
+    /// `payload_reset_percentage` (default: `None`) on `percentage_command_topic`.
+    ResetPercentage,
+    /// One of the `preset_modes`, received on `preset_mode_command_topic`.
+    SetPresetMode(String),
+    /// `payload_reset_preset_mode` (default: `None`) on `preset_mode_command_topic`.
+    ResetPresetMode,
+    /// `payload_oscillation_on` (default: `oscillate_on`) or `payload_oscillation_off` (default: `oscillate_off`)
+    /// on `oscillation_command_topic`.
+    SetOscillation(bool),
+    /// Received on `direction_command_topic`.
+    SetDirection(FanDirection),
+}
+
+/// Directions of a fan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FanDirection {
+    /// `forward`
+    Forward,
+    /// `reverse`
+    Reverse,
+}
+
+impl EntityCommands for Fan {
+    type Command = FanCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(
+            &self.topic_prefix,
+            [
+                Some(&self.command_topic),
+                self.percentage_command_topic.as_deref(),
+                self.preset_mode_command_topic.as_deref(),
+                self.oscillation_command_topic.as_deref(),
+                self.direction_command_topic.as_deref(),
+            ],
+        )
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<FanCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        if received.is_on(Some(&self.command_topic)) {
+            Some(received.choose([
+                (self.payload_on.as_deref().unwrap_or("ON"), FanCommand::On),
+                (
+                    self.payload_off.as_deref().unwrap_or("OFF"),
+                    FanCommand::Off,
+                ),
+            ]))
+        } else if received.is_on(self.percentage_command_topic.as_deref()) {
+            let reset = self.payload_reset_percentage.as_deref().unwrap_or("None");
+            Some(match received.text() {
+                Ok(text) if text == reset => Ok(FanCommand::ResetPercentage),
+                _ => {
+                    // the speed range starts at the first speed above off
+                    let min = self.speed_range_min.unwrap_or(1);
+                    received
+                        .percentage(min - 1, self.speed_range_max.unwrap_or(100))
+                        .map(FanCommand::SetPercentage)
+                }
+            })
+        } else if received.is_on(self.preset_mode_command_topic.as_deref()) {
+            let reset = self.payload_reset_preset_mode.as_deref().unwrap_or("None");
+            Some(received.text().and_then(|mode| match &self.preset_modes {
+                _ if mode == reset => Ok(FanCommand::ResetPresetMode),
+                Some(modes) if !modes.iter().any(|preset| preset == mode) => {
+                    Err(CommandError::UnknownPayload(mode.to_string()))
+                }
+                _ => Ok(FanCommand::SetPresetMode(mode.to_string())),
+            }))
+        } else if received.is_on(self.oscillation_command_topic.as_deref()) {
+            Some(
+                received.choose([
+                    (
+                        self.payload_oscillation_on
+                            .as_deref()
+                            .unwrap_or("oscillate_on"),
+                        FanCommand::SetOscillation(true),
+                    ),
+                    (
+                        self.payload_oscillation_off
+                            .as_deref()
+                            .unwrap_or("oscillate_off"),
+                        FanCommand::SetOscillation(false),
+                    ),
+                ]),
+            )
+        } else if received.is_on(self.direction_command_topic.as_deref()) {
+            Some(received.choose([
+                ("forward", FanCommand::SetDirection(FanDirection::Forward)),
+                ("reverse", FanCommand::SetDirection(FanDirection::Reverse)),
+            ]))
+        } else {
+            None
+        }
+    }
+}
+
+/// Commands of a climate device.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClimateCommand {
+    /// `payload_on` (default: `ON`) or `payload_off` (default: `OFF`) on `power_command_topic`.
+    SetPower(bool),
+    /// One of the `modes`, received on `mode_command_topic`.
+    SetMode(HvacMode),
+    /// Received on `temperature_command_topic`.
+    SetTemperature(Decimal),
+    /// Received on `temperature_low_command_topic`.
+    SetTemperatureLow(Decimal),
+    /// Received on `temperature_high_command_topic`.
+    SetTemperatureHigh(Decimal),
+    /// Received on `target_humidity_command_topic`.
+    SetHumidity(Decimal),
+    /// One of the `fan_modes`, received on `fan_mode_command_topic`.
+    SetFanMode(FanMode),
+    /// One of the `swing_modes`, received on `swing_mode_command_topic`.
+    SetSwingMode(SwingMode),
+    /// One of the `preset_modes`, received on `preset_mode_command_topic`.
+    SetPresetMode(PresetMode),
+}
+
+impl EntityCommands for Climate {
+    type Command = ClimateCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(
+            &self.topic_prefix,
+            [
+                self.power_command_topic.as_deref(),
+                self.mode_command_topic.as_deref(),
+                self.temperature_command_topic.as_deref(),
+                self.temperature_low_command_topic.as_deref(),
+                self.temperature_high_command_topic.as_deref(),
+                self.target_humidity_command_topic.as_deref(),
+                self.fan_mode_command_topic.as_deref(),
+                self.swing_mode_command_topic.as_deref(),
+                self.preset_mode_command_topic.as_deref(),
+            ],
+        )
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<ClimateCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        // a mode should be one of the configured modes, any mode Home Assistant knows otherwise
+        fn mode<M: FromStr + PartialEq>(
+            received: &Received,
+            modes: &Option<Vec<M>>,
+        ) -> Result<M, CommandError> {
+            let text = received.text()?;
+            let unknown = || CommandError::UnknownPayload(text.to_string());
+            let mode = text.parse().map_err(|_| unknown())?;
+            match modes {
+                Some(modes) if !modes.contains(&mode) => Err(unknown()),
+                _ => Ok(mode),
+            }
+        }
+        let command = if received.is_on(self.power_command_topic.as_deref()) {
+            received.choose([
+                (
+                    self.payload_on.as_deref().unwrap_or("ON"),
+                    ClimateCommand::SetPower(true),
+                ),
+                (
+                    self.payload_off.as_deref().unwrap_or("OFF"),
+                    ClimateCommand::SetPower(false),
+                ),
+            ])
+        } else if received.is_on(self.mode_command_topic.as_deref()) {
+            mode(&received, &self.modes).map(ClimateCommand::SetMode)
+        } else if received.is_on(self.temperature_command_topic.as_deref()) {
+            received.decimal().map(ClimateCommand::SetTemperature)
+        } else if received.is_on(self.temperature_low_command_topic.as_deref()) {
+            received.decimal().map(ClimateCommand::SetTemperatureLow)
+        } else if received.is_on(self.temperature_high_command_topic.as_deref()) {
+            received.decimal().map(ClimateCommand::SetTemperatureHigh)
+        } else if received.is_on(self.target_humidity_command_topic.as_deref()) {
+            received.decimal().map(ClimateCommand::SetHumidity)
+        } else if received.is_on(self.fan_mode_command_topic.as_deref()) {
+            mode(&received, &self.fan_modes).map(ClimateCommand::SetFanMode)
+        } else if received.is_on(self.swing_mode_command_topic.as_deref()) {
+            mode(&received, &self.swing_modes).map(ClimateCommand::SetSwingMode)
+        } else if received.is_on(self.preset_mode_command_topic.as_deref()) {
+            mode(&received, &self.preset_modes).map(ClimateCommand::SetPresetMode)
+        } else {
+            return None;
+        };
+        Some(command)
+    }
+}
+
+/// Command of a select: one of its `options`, received on `command_topic`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectCommand(pub String);
+
+impl EntityCommands for Select {
+    type Command = SelectCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [Some(&self.command_topic)])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<SelectCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received.is_on(Some(&self.command_topic)).then(|| {
+            let option = received.text()?;
+            if self.options.iter().any(|known| known == option) {
+                Ok(SelectCommand(option.to_string()))
+            } else {
+                Err(CommandError::UnknownPayload(option.to_string()))
+            }
+        })
+    }
+}
+
+/// Command of a number: the value received on `command_topic`, within `min` and `max` when set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberCommand(pub Decimal);
+
+impl EntityCommands for Number {
+    type Command = NumberCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [Some(&self.command_topic)])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<NumberCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received.is_on(Some(&self.command_topic)).then(|| {
+            let value = received.decimal()?;
+            let in_range =
+                self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max);
+            in_range
+                .then_some(NumberCommand(value))
+                .ok_or_else(|| CommandError::InvalidValue(value.to_string()))
+        })
+    }
+}
+
+/// Command of a text: the value received on `command_topic`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextCommand(pub String);
+
+impl EntityCommands for Text {
+    type Command = TextCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [Some(&self.command_topic)])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<TextCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received
+            .is_on(Some(&self.command_topic))
+            .then(|| received.text().map(|text| TextCommand(text.to_string())))
+    }
+}
+
+/// Command of a button: `payload_press` (default: `PRESS`) on `command_topic`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ButtonCommand;
+
+impl EntityCommands for Button {
+    type Command = ButtonCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [Some(&self.command_topic)])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<ButtonCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received.is_on(Some(&self.command_topic)).then(|| {
+            received.choose([(
+                self.payload_press.as_deref().unwrap_or("PRESS"),
+                ButtonCommand,
+            )])
+        })
+    }
+}
+
+/// Commands of a siren, received on `command_topic`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SirenCommand {
+    /// `payload_on` (default: `ON`), or a JSON object with the `payload_on` state and the optional turn on parameters.
+    On {
+        /// One of the `available_tones`.
+        tone: Option<String>,
+        /// The volume, from 0 to 1.
+        volume_level: Option<Decimal>,
+        /// The duration in seconds.
+        duration: Option<u32>,
+    },
+    /// `payload_off` (default: `OFF`), or a JSON object with the `payload_off` state.
+    Off,
+}
+
+impl Siren {
+    fn decode_json_command(&self, text: &str) -> Result<SirenCommand, CommandError> {
+        let unknown = || CommandError::UnknownPayload(text.to_string());
+        let Ok(Value::Object(command)) = serde_json::from_str::<Value>(text) else {
+            return Err(unknown());
+        };
+        let state = command.get("state").and_then(Value::as_str);
+        if state == Some(self.payload_off.as_deref().unwrap_or("OFF")) {
+            return Ok(SirenCommand::Off);
+        }
+        if state != Some(self.payload_on.as_deref().unwrap_or("ON")) {
+            return Err(unknown());
+        }
+        let tone = command.get("tone").and_then(Value::as_str);
+        if let (Some(tone), Some(tones)) = (tone, &self.available_tones) {
+            if !tones.iter().any(|known| known == tone) {
+                return Err(CommandError::InvalidValue(tone.to_string()));
+            }
+        }
+        let volume_level = match command.get("volume_level") {
+            Some(volume) => Some(
+                serde_json::from_value::<Decimal>(volume.clone())
+                    .ok()
+                    .filter(|level| (Decimal::ZERO..=Decimal::ONE).contains(level))
+                    .ok_or_else(|| CommandError::InvalidValue(volume.to_string()))?,
+            ),
+            None => None,
+        };
+        let duration = match command.get("duration") {
+            Some(duration) => Some(
+                duration
+                    .as_u64()
+                    .and_then(|seconds| u32::try_from(seconds).ok())
+                    .ok_or_else(|| CommandError::InvalidValue(duration.to_string()))?,
+            ),
+            None => None,
+        };
+        Ok(SirenCommand::On {
+            tone: tone.map(str::to_string),
+            volume_level,
+            duration,
+        })
+    }
+}
+
+impl EntityCommands for Siren {
+    type Command = SirenCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [self.command_topic.as_deref()])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<SirenCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received.is_on(self.command_topic.as_deref()).then(|| {
+            let text = received.text()?;
+            if text == self.payload_on.as_deref().unwrap_or("ON") {
+                Ok(SirenCommand::On {
+                    tone: None,
+                    volume_level: None,
+                    duration: None,
+                })
+            } else if text == self.payload_off.as_deref().unwrap_or("OFF") {
+                Ok(SirenCommand::Off)
+            } else {
+                self.decode_json_command(text)
+            }
+        })
+    }
+}
+
+/// Commands of a valve, received on `command_topic`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValveCommand {
+    /// `payload_open` (default: `OPEN`).
+    Open,
+    /// `payload_close` (default: `CLOSE`).
+    Close,
+    /// `payload_stop` (default: `STOP`).
+    Stop,
+    /// Position in percent, from closed (0) to open (100), when the valve `reports_position`,
+    /// scaled between `position_closed` (default: 0) and `position_open` (default: 100).
+    SetPosition(u8),
+}
+
+impl EntityCommands for Valve {
+    type Command = ValveCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(&self.topic_prefix, [self.command_topic.as_deref()])
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<ValveCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        received.is_on(self.command_topic.as_deref()).then(|| {
+            let command = received.choose([
+                (
+                    self.payload_open.as_deref().unwrap_or("OPEN"),
+                    ValveCommand::Open,
+                ),
+                (
+                    self.payload_close.as_deref().unwrap_or("CLOSE"),
+                    ValveCommand::Close,
+                ),
+                (
+                    self.payload_stop.as_deref().unwrap_or("STOP"),
+                    ValveCommand::Stop,
+                ),
+            ]);
+            match command {
+                Err(CommandError::UnknownPayload(_)) if self.reports_position == Some(true) => {
+                    received
+                        .percentage(
+                            self.position_closed.unwrap_or(0),
+                            self.position_open.unwrap_or(100),
+                        )
+                        .map(ValveCommand::SetPosition)
+                }
+                command => command,
+            }
+        })
+    }
+}
+
+/// Commands of a vacuum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VacuumCommand {
+    /// `payload_start` (default: `start`) on `command_topic`.
+    Start,
+    /// `payload_pause` (default: `pause`) on `command_topic`.
+    Pause,
+    /// `payload_stop` (default: `stop`) on `command_topic`.
+    Stop,
+    /// `payload_return_to_base` (default: `return_to_base`) on `command_topic`.
+    ReturnToBase,
+    /// `payload_clean_spot` (default: `clean_spot`) on `command_topic`.
+    CleanSpot,
+    /// `payload_locate` (default: `locate`) on `command_topic`.
+    Locate,
+    /// One of the `fan_speed_list`, received on `set_fan_speed_topic`.
+    SetFanSpeed(String),
+    /// Received on `send_command_topic`, either the command alone
+    /// or a JSON object with the `command` and its `params`.
+    SendCommand {
+        command: String,
+        params: Option<Value>,
+    },
+}
+
+/// Decodes the command sent to a vacuum, with its parameters when it is a JSON object.
+fn decode_send_command(text: &str) -> VacuumCommand {
+    if let Ok(Value::Object(mut object)) = serde_json::from_str::<Value>(text) {
+        if let Some(Value::String(command)) = object.remove("command") {
+            return VacuumCommand::SendCommand {
+                command,
+                params: object.remove("params"),
+            };
+        }
+    }
+    VacuumCommand::SendCommand {
+        command: text.to_string(),
+        params: None,
+    }
+}
+
+impl EntityCommands for Vacuum {
+    type Command = VacuumCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(
+            &self.topic_prefix,
+            [
+                self.command_topic.as_deref(),
+                self.set_fan_speed_topic.as_deref(),
+                self.send_command_topic.as_deref(),
+            ],
+        )
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<VacuumCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        if received.is_on(self.command_topic.as_deref()) {
+            Some(
+                received.choose([
+                    (
+                        self.payload_start.as_deref().unwrap_or("start"),
+                        VacuumCommand::Start,
+                    ),
+                    (
+                        self.payload_pause.as_deref().unwrap_or("pause"),
+                        VacuumCommand::Pause,
+                    ),
+                    (
+                        self.payload_stop.as_deref().unwrap_or("stop"),
+                        VacuumCommand::Stop,
+                    ),
+                    (
+                        self.payload_return_to_base
+                            .as_deref()
+                            .unwrap_or("return_to_base"),
+                        VacuumCommand::ReturnToBase,
+                    ),
+                    (
+                        self.payload_clean_spot.as_deref().unwrap_or("clean_spot"),
+                        VacuumCommand::CleanSpot,
+                    ),
+                    (
+                        self.payload_locate.as_deref().unwrap_or("locate"),
+                        VacuumCommand::Locate,
+                    ),
+                ]),
+            )
+        } else if received.is_on(self.set_fan_speed_topic.as_deref()) {
+            Some(
+                received
+                    .text()
+                    .and_then(|speed| match &self.fan_speed_list {
+                        Some(speeds) if !speeds.iter().any(|known| known == speed) => {
+                            Err(CommandError::UnknownPayload(speed.to_string()))
+                        }
+                        _ => Ok(VacuumCommand::SetFanSpeed(speed.to_string())),
+                    }),
+            )
+        } else if received.is_on(self.send_command_topic.as_deref()) {
+            Some(received.text().map(decode_send_command))
+        } else {
+            None
+        }
+    }
+}
+
+/// Commands of a lawn mower, each received on its own topic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LawnMowerCommand {
+    /// `start_mowing`, rendered by `start_mowing_template`, on `start_mowing_command_topic`.
+    StartMowing,
+    /// `pause`, rendered by `pause_command_template`, on `pause_command_topic`.
+    Pause,
+    /// `dock`, rendered by `dock_command_template`, on `dock_command_topic`.
+    Dock,
+}
+
+/// The payload Home Assistant publishes for the value: the value rendered by the command template, or the value itself.
+/// The payload isn't known when the template can't be rendered.
+#[cfg(feature = "templates")]
+fn command_payload(command_template: &Option<String>, value: &str) -> Option<String> {
+    use crate::template::{TemplateRenderer, TemplateVariables};
+
+    match command_template {
+        Some(template) => TemplateRenderer::new()
+            .render(template, &TemplateVariables::new().value(value))
+            .ok(),
+        None => Some(value.to_string()),
+    }
+}
+
+/// The payload Home Assistant publishes for the value: the value itself, when there is no command template.
+/// Command templates are only rendered with the `templates` feature.
+#[cfg(not(feature = "templates"))]
+fn command_payload(command_template: &Option<String>, value: &str) -> Option<String> {
+    command_template.is_none().then(|| value.to_string())
+}
+
+impl EntityCommands for LawnMower {
+    type Command = LawnMowerCommand;
+
+    fn command_topics(&self) -> Vec<String> {
+        resolved(
+            &self.topic_prefix,
+            [
+                self.start_mowing_command_topic.as_deref(),
+                self.pause_command_topic.as_deref(),
+                self.dock_command_topic.as_deref(),
+            ],
+        )
+    }
+
+    fn decode_command(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<Result<LawnMowerCommand, CommandError>> {
+        let received = Received::new(&self.topic_prefix, topic, payload);
+        let (command, command_template, value) =
+            if received.is_on(self.start_mowing_command_topic.as_deref()) {
+                (
+                    LawnMowerCommand::StartMowing,
+                    &self.start_mowing_template,
+                    "start_mowing",
+                )
+            } else if received.is_on(self.pause_command_topic.as_deref()) {
+                (
+                    LawnMowerCommand::Pause,
+                    &self.pause_command_template,
+                    "pause",
+                )
+            } else if received.is_on(self.dock_command_topic.as_deref()) {
+                (LawnMowerCommand::Dock, &self.dock_command_template, "dock")
+            } else {
+                return None;
+            };
+        Some(
+            received
+                .text()
+                .and_then(|text| match command_payload(command_template, value) {
+                    Some(expected) if expected != text => {
+                        Err(CommandError::UnknownPayload(text.to_string()))
+                    }
+                    _ => Ok(command),
+                }),
+        )
+    }
+}
+
+/// A command decoded by a [`CommandRouter`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    AlarmControlPanel(AlarmCommand),
+    Button(ButtonCommand),
+    Climate(ClimateCommand),
+    Cover(CoverCommand),
+    Fan(FanCommand),
+    LawnMower(LawnMowerCommand),
+    Lock(LockCommand),
+    Number(NumberCommand),
+    Select(SelectCommand),
+    Siren(SirenCommand),
+    Switch(SwitchCommand),
+    Text(TextCommand),
+    Vacuum(VacuumCommand),
+    Valve(ValveCommand),
+}
+
+/// The command topics of an entity, none for entities which don't receive commands.
+fn entity_command_topics(entity: &Entity) -> Vec<String> {
+    match entity {
+        Entity::AlarmControlPanel(panel) => panel.command_topics(),
+        Entity::Button(button) => button.command_topics(),
+        Entity::Climate(climate) => climate.command_topics(),
+        Entity::Cover(cover) => cover.command_topics(),
+        Entity::Fan(fan) => fan.command_topics(),
+        Entity::LawnMower(lawn_mower) => lawn_mower.command_topics(),
+        Entity::Lock(lock) => lock.command_topics(),
+        Entity::Number(number) => number.command_topics(),
+        Entity::Select(select) => select.command_topics(),
+        Entity::Siren(siren) => siren.command_topics(),
+        Entity::Switch(switch) => switch.command_topics(),
+        Entity::Text(text) => text.command_topics(),
+        Entity::Vacuum(vacuum) => vacuum.command_topics(),
+        Entity::Valve(valve) => valve.command_topics(),
+        _ => Vec::new(),
+    }
+}
+
+fn decode_entity_command(
+    entity: &Entity,
+    topic: &str,
+    payload: &[u8],
+) -> Option<Result<Command, CommandError>> {
+    fn decode<E: EntityCommands>(
+        entity: &E,
+        topic: &str,
+        payload: &[u8],
+        command: fn(E::Command) -> Command,
+    ) -> Option<Result<Command, CommandError>> {
+        entity
+            .decode_command(topic, payload)
+            .map(|result| result.map(command))
+    }
+
+    match entity {
+        Entity::AlarmControlPanel(panel) => {
+            decode(panel, topic, payload, Command::AlarmControlPanel)
+        }
+        Entity::Button(button) => decode(button, topic, payload, Command::Button),
+        Entity::Climate(climate) => decode(climate, topic, payload, Command::Climate),
+        Entity::Cover(cover) => decode(cover, topic, payload, Command::Cover),
+        Entity::Fan(fan) => decode(fan, topic, payload, Command::Fan),
+        Entity::LawnMower(lawn_mower) => decode(lawn_mower, topic, payload, Command::LawnMower),
+        Entity::Lock(lock) => decode(lock, topic, payload, Command::Lock),
+        Entity::Number(number) => decode(number, topic, payload, Command::Number),
+        Entity::Select(select) => decode(select, topic, payload, Command::Select),
+        Entity::Siren(siren) => decode(siren, topic, payload, Command::Siren),
+        Entity::Switch(switch) => decode(switch, topic, payload, Command::Switch),
+        Entity::Text(text) => decode(text, topic, payload, Command::Text),
+        Entity::Vacuum(vacuum) => decode(vacuum, topic, payload, Command::Vacuum),
+        Entity::Valve(valve) => decode(valve, topic, payload, Command::Valve),
+        _ => None,
+    }
+}
+
+/// Routes the messages received on the command topics of entities to typed commands.
+#[derive(Clone, Debug, Default)]
+pub struct CommandRouter {
+    entities: Vec<Entity>,
+}
+
+impl CommandRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an entity whose commands are routed.
+    pub fn add_entity<E: Into<Entity>>(mut self, entity: E) -> Self {
+        self.entities.push(entity.into());
+        self
+    }
+
+    /// The command topics of every entity, `~` being resolved, with the QoS level of the entity.
+    pub fn topics(&self) -> Vec<(String, Qos)> {
+        self.entities
+            .iter()
+            .flat_map(|entity| {
+                let qos = entity.get_qos().unwrap_or(Qos::AtMostOnce);
+                entity_command_topics(entity)
+                    .into_iter()
+                    .map(move |topic| (topic, qos))
+            })
+            .collect()
+    }
+
+    /// Subscribes to the command topics of every entity.
+    /// Received messages must be handed back to the router by the caller, see [`CommandRouter::route`].
+    pub async fn subscribe<T: DiscoveryTransport>(&self, client: &T) -> Result<(), T::Error> {
+        for (topic, qos) in self.topics() {
+            client.subscribe(topic, qos).await?;
+        }
+        Ok(())
+    }
+
+    /// Decodes a received message with the configuration of the entity it is addressed to,
+    /// or returns `None` when the topic isn't the command topic of any entity.
+    pub fn route(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Option<(&Entity, Result<Command, CommandError>)> {
+        self.entities.iter().find_map(|entity| {
+            decode_entity_command(entity, topic, payload).map(|command| (entity, command))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn can_decode_commands_with_the_configured_payloads() {
+        let switch = Switch::default()
+            .topic_prefix("garden/irrigation")
+            .command_topic("~/set")
+            .payload_on("start");
+        assert_eq!(
+            switch.decode_command("garden/irrigation/set", b"start"),
+            Some(Ok(SwitchCommand::On))
+        );
+        assert_eq!(
+            switch.decode_command("garden/irrigation/set", b"OFF"),
+            Some(Ok(SwitchCommand::Off))
+        );
+        assert_eq!(
+            switch.decode_command("garden/irrigation/set", b"ON"),
+            Some(Err(CommandError::UnknownPayload("ON".to_string())))
+        );
+        assert_eq!(
+            switch.decode_command("garden/irrigation/state", b"ON"),
+            None
+        );
+    }
+
+    #[test]
+    fn can_decode_positions_as_percentages() {
+        let cover = Cover::default()
+            .command_topic("garage/set")
+            .set_position_topic("garage/position/set")
+            .position_open(255);
+        assert_eq!(
+            cover.decode_command("garage/position/set", b"51"),
+            Some(Ok(CoverCommand::SetPosition(20)))
+        );
+        assert_eq!(
+            cover.decode_command("garage/position/set", b"300"),
+            Some(Err(CommandError::InvalidValue("300".to_string())))
+        );
+
+        let fan = Fan::default()
+            .command_topic("fan/set")
+            .percentage_command_topic("fan/speed/set")
+            .speed_range_min(1)
+            .speed_range_max(4);
+        assert_eq!(
+            fan.decode_command("fan/speed/set", b"2"),
+            Some(Ok(FanCommand::SetPercentage(50)))
+        );
+        assert_eq!(
+            fan.decode_command("fan/speed/set", b"None"),
+            Some(Ok(FanCommand::ResetPercentage))
+        );
+    }
+
+    #[test]
+    fn can_decode_a_siren_turned_on_with_parameters() {
+        let siren = Siren::default()
+            .command_topic("siren/set")
+            .available_tones(vec!["ping".to_string(), "fire".to_string()]);
+        assert_eq!(
+            siren.decode_command(
+                "siren/set",
+                br#"{"state": "ON", "tone": "fire", "volume_level": 0.5, "duration": 10}"#
+            ),
+            Some(Ok(SirenCommand::On {
+                tone: Some("fire".to_string()),
+                volume_level: Some(dec!(0.5)),
+                duration: Some(10)
+            }))
+        );
+        assert_eq!(
+            siren.decode_command("siren/set", br#"{"state": "OFF"}"#),
+            Some(Ok(SirenCommand::Off))
+        );
+    }
+
+    #[test]
+    fn cannot_decode_a_siren_turned_on_with_an_invalid_volume_level() {
+        let siren = Siren::default().command_topic("siren/set");
+        assert_eq!(
+            siren.decode_command("siren/set", br#"{"state": "ON", "volume_level": 1.5}"#),
+            Some(Err(CommandError::InvalidValue("1.5".to_string())))
+        );
+        assert_eq!(
+            siren.decode_command("siren/set", br#"{"state": "ON", "volume_level": -0.1}"#),
+            Some(Err(CommandError::InvalidValue("-0.1".to_string())))
+        );
+    }
+
+    #[test]
+    fn cannot_decode_a_siren_turned_on_with_an_invalid_duration() {
+        let siren = Siren::default().command_topic("siren/set");
+        for (payload, duration) in [
+            (br#"{"state": "ON", "duration": -1}"#.as_slice(), "-1"),
+            (br#"{"state": "ON", "duration": 1.5}"#, "1.5"),
+            (br#"{"state": "ON", "duration": "10"}"#, r#""10""#),
+        ] {
+            assert_eq!(
+                siren.decode_command("siren/set", payload),
+                Some(Err(CommandError::InvalidValue(duration.to_string())))
+            );
+        }
+    }
+
+    #[test]
+    fn can_decode_an_alarm_action_with_its_code() {
+        let panel = AlarmControlPanel::default()
+            .command_topic("alarm/set")
+            .payload_arm_home("HOME");
+        assert_eq!(
+            panel.decode_command("alarm/set", b"HOME"),
+            Some(Ok(AlarmCommand {
+                action: AlarmAction::ArmHome,
+                code: None
+            }))
+        );
+        assert_eq!(
+            panel.decode_command("alarm/set", br#"{"action": "DISARM", "code": "1234"}"#),
+            Some(Ok(AlarmCommand {
+                action: AlarmAction::Disarm,
+                code: Some("1234".to_string())
+            }))
+        );
+        assert_eq!(
+            panel.decode_command("alarm/set", b"ARM_HOME"),
+            Some(Err(CommandError::UnknownPayload("ARM_HOME".to_string())))
+        );
+    }
+
+    #[test]
+    fn can_decode_a_vacuum_command_with_its_params() {
+        let vacuum = Vacuum::default().send_command_topic("vacuum/send_command");
+        assert_eq!(
+            vacuum.decode_command(
+                "vacuum/send_command",
+                br#"{"command": "clean_area", "params": {"area": "kitchen"}}"#
+            ),
+            Some(Ok(VacuumCommand::SendCommand {
+                command: "clean_area".to_string(),
+                params: Some(serde_json::json!({"area": "kitchen"}))
+            }))
+        );
+        assert_eq!(
+            vacuum.decode_command("vacuum/send_command", b"empty_bin"),
+            Some(Ok(VacuumCommand::SendCommand {
+                command: "empty_bin".to_string(),
+                params: None
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "templates")]
+    fn can_decode_lawn_mower_commands_rendered_by_their_template() {
+        let mower = LawnMower::default()
+            .dock_command_topic("mower/dock")
+            .dock_command_template(r#"{"action": "{{ value }}"}"#)
+            .pause_command_topic("mower/pause");
+        assert_eq!(
+            mower.decode_command("mower/dock", br#"{"action": "dock"}"#),
+            Some(Ok(LawnMowerCommand::Dock))
+        );
+        assert_eq!(
+            mower.decode_command("mower/dock", b"dock"),
+            Some(Err(CommandError::UnknownPayload("dock".to_string())))
+        );
+        assert_eq!(
+            mower.decode_command("mower/pause", b"pause"),
+            Some(Ok(LawnMowerCommand::Pause))
+        );
+    }
+
+    #[test]
+    fn can_decode_climate_modes() {
+        let climate = Climate::default()
+            .mode_command_topic("hvac/mode/set")
+            .modes(vec![HvacMode::Off, HvacMode::Heat])
+            .fan_mode_command_topic("hvac/fan/set");
+        assert_eq!(
+            climate.decode_command("hvac/mode/set", b"heat"),
+            Some(Ok(ClimateCommand::SetMode(HvacMode::Heat)))
+        );
+        assert_eq!(
+            climate.decode_command("hvac/mode/set", b"cool"),
+            Some(Err(CommandError::UnknownPayload("cool".to_string())))
+        );
+        assert_eq!(
+            climate.decode_command("hvac/fan/set", b"turbo"),
+            Some(Ok(ClimateCommand::SetFanMode(FanMode::Other(
+                "turbo".to_string()
+            ))))
+        );
+    }
+
+    #[test]
+    fn can_route_commands_to_their_entity() {
+        let router = CommandRouter::new()
+            .add_entity(
+                Select::default()
+                    .unique_id("program")
+                    .command_topic("washer/program/set")
+                    .options(vec!["eco".to_string(), "quick".to_string()])
+                    .qos(Qos::AtLeastOnce),
+            )
+            .add_entity(
+                Number::default()
+                    .unique_id("temperature")
+                    .command_topic("washer/temperature/set")
+                    .max(dec!(90)),
+            )
+            .add_entity(crate::mqtt::sensor::Sensor::default().state_topic("washer/state"));
+
+        assert_eq!(
+            router.topics(),
+            vec![
+                ("washer/program/set".to_string(), Qos::AtLeastOnce),
+                ("washer/temperature/set".to_string(), Qos::AtMostOnce),
+            ]
+        );
+        let (entity, command) = router.route("washer/temperature/set", b"40").unwrap();
+        assert_eq!(entity, &router.entities[1]);
+        assert_eq!(command, Ok(Command::Number(NumberCommand(dec!(40)))));
+        assert_eq!(
+            router.route("washer/temperature/set", b"95").unwrap().1,
+            Err(CommandError::InvalidValue("95".to_string()))
+        );
+        assert_eq!(
+            router.route("washer/program/set", b"cotton").unwrap().1,
+            Err(CommandError::UnknownPayload("cotton".to_string()))
+        );
+        assert!(router.route("washer/state", b"idle").is_none());
+    }
+}
@@ -0,0 +1,252 @@
+use serde::ser::Error as _;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::mqtt::common::{Device, Origin, Qos};
+use crate::publisher::derived_object_id;
+use crate::Entity;
+
+/// A device-based discovery payload, announcing many components of a device in a single message
+/// published on `<discovery_prefix>/device/<object_id>/config`.
+///
+/// The device and origin are shared by all the components, they are removed from each component configuration.
+/// Every component must have a unique ID, used as its key in the `cmps` map. Device triggers and tags,
+/// which have none, are keyed by an object ID derived from the device, as in their discovery topic.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#device-discovery-payload)
+#[derive(Clone)]
+pub struct DeviceDiscovery {
+    /// The ID of the device, used as `<object_id>` in the discovery topic.
+    pub object_id: String,
+    /// Information about the device the components are a part of.
+    pub device: Device,
+    /// Information about the origin that supplies the components.
+    pub origin: Origin,
+    /// Replaces `~` with this value in any MQTT topic attribute of the components.
+    pub topic_prefix: Option<String>,
+    /// The MQTT topic shared by the components to receive state updates.
+    pub state_topic: Option<String>,
+    /// The maximum QoS level shared by the components.
+    pub qos: Option<Qos>,
+    /// The components of the device.
+    pub components: Vec<Entity>,
+}
+
+impl DeviceDiscovery {
+    pub fn new<S: Into<String>>(object_id: S, device: Device, origin: Origin) -> Self {
+        Self {
+            object_id: object_id.into(),
+            device,
+            origin,
+            topic_prefix: None,
+            state_topic: None,
+            qos: None,
+            components: Vec::new(),
+        }
+    }
+
+    /// Replaces `~` with this value in any MQTT topic attribute of the components.
+    pub fn topic_prefix<S: Into<String>>(mut self, topic_prefix: S) -> Self {
+        self.topic_prefix = Some(topic_prefix.into());
+        self
+    }
+
+    /// The MQTT topic shared by the components to receive state updates.
+    pub fn state_topic<S: Into<String>>(mut self, state_topic: S) -> Self {
+        self.state_topic = Some(state_topic.into());
+        self
+    }
+
+    /// The maximum QoS level shared by the components.
+    pub fn qos(mut self, qos: Qos) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Adds a component to the device.
+    pub fn add_component<E: Into<Entity>>(mut self, component: E) -> Self {
+        self.components.push(component.into());
+        self
+    }
+
+    /// Adds many components to the device.
+    pub fn components<E: Into<Entity>>(mut self, components: Vec<E>) -> Self {
+        self.components
+            .extend(components.into_iter().map(|component| component.into()));
+        self
+    }
+}
+
+impl Serialize for DeviceDiscovery {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut components = Map::new();
+        for component in &self.components {
+            let Value::Object(mut attributes) =
+                component.get_attributes().map_err(S::Error::custom)?
+            else {
+                return Err(S::Error::custom(
+                    "component configuration should be an object",
+                ));
+            };
+            attributes.remove("dev");
+            attributes.remove("o");
+            let key = match derived_object_id(component, &self.device) {
+                Some(object_id) => object_id,
+                None => attributes
+                    .get("uniq_id")
+                    .and_then(|unique_id| unique_id.as_str())
+                    .ok_or_else(|| {
+                        S::Error::custom(format!(
+                            "{} component should have an attribute 'uniq_id'",
+                            component.get_component_name()
+                        ))
+                    })?
+                    .to_string(),
+            };
+            if components.contains_key(&key) {
+                return Err(S::Error::custom(format!("duplicate component '{key}'")));
+            }
+            components.insert(key, Value::Object(attributes));
+        }
+
+        let mut payload = Map::new();
+        payload.insert(
+            "dev".to_string(),
+            serde_json::to_value(&self.device).map_err(S::Error::custom)?,
+        );
+        payload.insert(
+            "o".to_string(),
+            serde_json::to_value(&self.origin).map_err(S::Error::custom)?,
+        );
+        if let Some(topic_prefix) = &self.topic_prefix {
+            payload.insert("~".to_string(), topic_prefix.clone().into());
+        }
+        if let Some(state_topic) = &self.state_topic {
+            payload.insert("stat_t".to_string(), state_topic.clone().into());
+        }
+        if let Some(qos) = &self.qos {
+            payload.insert(
+                "qos".to_string(),
+                serde_json::to_value(qos).map_err(S::Error::custom)?,
+            );
+        }
+        payload.insert("cmps".to_string(), Value::Object(components));
+        payload.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::mqtt::{
+        climate::Climate, common::DeviceConnection, device_trigger::DeviceTrigger, sensor::Sensor,
+        units::TempUnit, units::Unit,
+    };
+
+    #[test]
+    fn can_serialize_a_device_discovery_payload() {
+        let device = Device::default()
+            .name("Thermostat")
+            .add_identifier("thermostat-01")
+            .add_connection(DeviceConnection::mac("09:AF:A4:54:F0:9D"));
+        let origin = Origin::new("Integration test").with_sw_version("0.0.1");
+        let discovery = DeviceDiscovery::new("thermostat-01", device.clone(), origin.clone())
+            .topic_prefix("thermostats/01")
+            .qos(Qos::AtLeastOnce)
+            .add_component(
+                Sensor::default()
+                    .device(device.clone())
+                    .origin(origin.clone())
+                    .unique_id("thermostat-01_temperature")
+                    .state_topic("~/state")
+                    .value_template("{{ value_json.temperature }}")
+                    .unit_of_measurement(Unit::Temperature(TempUnit::Celsius)),
+            )
+            .add_component(
+                Climate::default()
+                    .device(device)
+                    .origin(origin)
+                    .unique_id("thermostat-01_climate"),
+            );
+
+        assert_json_eq!(
+            serde_json::to_value(&discovery).unwrap(),
+            json!({
+                "dev": {
+                    "name": "Thermostat",
+                    "ids": ["thermostat-01"],
+                    "cns": [["mac", "09:AF:A4:54:F0:9D"]]
+                },
+                "o": {
+                    "name": "Integration test",
+                    "sw": "0.0.1"
+                },
+                "~": "thermostats/01",
+                "qos": 1,
+                "cmps": {
+                    "thermostat-01_temperature": {
+                        "platform": "sensor",
+                        "avty_mode": "all",
+                        "avty": [],
+                        "stat_t": "~/state",
+                        "uniq_id": "thermostat-01_temperature",
+                        "val_tpl": "{{ value_json.temperature }}",
+                        "unit_of_meas": "°C"
+                    },
+                    "thermostat-01_climate": {
+                        "platform": "climate",
+                        "avty_mode": "all",
+                        "avty": [],
+                        "uniq_id": "thermostat-01_climate"
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn cannot_serialize_a_component_without_unique_id() {
+        let discovery = DeviceDiscovery::new("thermostat-01", Device::default(), Origin::default())
+            .add_component(Sensor::default());
+        assert!(serde_json::to_value(&discovery).is_err());
+    }
+    #[test]
+    fn cannot_serialize_components_with_the_same_unique_id() {
+        let discovery = DeviceDiscovery::new("thermostat-01", Device::default(), Origin::default())
+            .add_component(Sensor::default().unique_id("temperature"))
+            .add_component(Sensor::default().unique_id("temperature"));
+        assert_eq!(
+            serde_json::to_value(&discovery).unwrap_err().to_string(),
+            "duplicate component 'temperature'"
+        );
+    }
+
+    #[test]
+    fn can_serialize_a_device_trigger_keyed_by_its_derived_object_id() {
+        let device = Device::default().add_identifier("remote-01");
+        let discovery = DeviceDiscovery::new("remote-01", device, Origin::default()).add_component(
+            DeviceTrigger::default()
+                .automation_type("trigger")
+                .topic("remote/action")
+                .r#type("button_short_press")
+                .subtype("button_1")
+                .payload("press_1"),
+        );
+        let payload = serde_json::to_value(&discovery).unwrap();
+        assert_json_eq!(
+            payload["cmps"]["remote-01_button_short_press_button_1"],
+            json!({
+                "platform": "device_automation",
+                "avty_mode": "all",
+                "avty": [],
+                "atype": "trigger",
+                "t": "remote/action",
+                "type": "button_short_press",
+                "stype": "button_1",
+                "pl": "press_1"
+            })
+        );
+    }
+}
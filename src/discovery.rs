@@ -0,0 +1,225 @@
+use std::fmt::{Display, Formatter};
+
+use serde_json::{Map, Value};
+
+use crate::{Entity, COMPONENTS};
+
+/// An entity read from a discovery topic and its payload.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscoveredEntity {
+    /// The optional `<node_id>` level of the discovery topic.
+    pub node_id: Option<String>,
+    /// The `<object_id>` level of the discovery topic.
+    pub object_id: String,
+    /// The configuration of the entity, with the `~` references expanded.
+    pub entity: Entity,
+}
+
+/// Errors raised while reading a discovery message.
+#[derive(Debug)]
+pub enum DiscoveryError {
+    /// The topic doesn't follow the `<discovery_prefix>/<component>/[<node_id>/]<object_id>/config` format.
+    InvalidTopic(String),
+    /// The component of the topic isn't supported.
+    UnknownComponent(String),
+    /// The payload is empty, meaning the entity has been removed.
+    EmptyPayload,
+    /// The payload isn't a valid configuration of the component.
+    Payload(serde_json::Error),
+}
+
+impl Display for DiscoveryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscoveryError::InvalidTopic(topic) => write!(f, "invalid discovery topic '{topic}'"),
+            DiscoveryError::UnknownComponent(component) => {
+                write!(f, "unknown component '{component}'")
+            }
+            DiscoveryError::EmptyPayload => write!(f, "empty discovery payload"),
+            DiscoveryError::Payload(error) => write!(f, "invalid discovery payload: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for DiscoveryError {}
+
+impl From<serde_json::Error> for DiscoveryError {
+    fn from(value: serde_json::Error) -> Self {
+        DiscoveryError::Payload(value)
+    }
+}
+
+/// The levels of a discovery topic.
+pub(crate) struct DiscoveryTopic<'a> {
+    pub component: &'a str,
+    pub node_id: Option<&'a str>,
+    pub object_id: &'a str,
+}
+
+/// Splits `<discovery_prefix>/<component>/[<node_id>/]<object_id>/config`.
+///
+/// As the discovery prefix is unknown, the topic is read from its end:
+/// the `<node_id>` level is only assumed when the level before it is a known component.
+pub(crate) fn parse_topic(topic: &str) -> Result<DiscoveryTopic<'_>, DiscoveryError> {
+    let levels: Vec<&str> = topic.split('/').collect();
+    let discovery_topic = match levels.as_slice() {
+        [_, .., component, node_id, object_id, "config"] if COMPONENTS.contains(component) => {
+            DiscoveryTopic {
+                component,
+                node_id: Some(node_id),
+                object_id,
+            }
+        }
+        [_, .., component, object_id, "config"] => DiscoveryTopic {
+            component,
+            node_id: None,
+            object_id,
+        },
+        _ => return Err(DiscoveryError::InvalidTopic(topic.to_string())),
+    };
+    if !COMPONENTS.contains(&discovery_topic.component) {
+        return Err(DiscoveryError::UnknownComponent(
+            discovery_topic.component.to_string(),
+        ));
+    }
+    Ok(discovery_topic)
+}
+
+/// Whether the attribute holds an MQTT topic, either abbreviated (`stat_t`, `t`) or not (`state_topic`).
+pub(crate) fn is_topic_attribute(name: &str) -> bool {
+    name == "t" || name.ends_with("_t") || name.ends_with("topic")
+}
+
+/// Replaces the `~` at the start or the end of the topic by the base topic, if any.
+pub(crate) fn resolve_topic(base: Option<&str>, topic: &str) -> String {
+    match base {
+        Some(base) => {
+            if let Some(suffix) = topic.strip_prefix('~') {
+                format!("{base}{suffix}")
+            } else if let Some(prefix) = topic.strip_suffix('~') {
+                format!("{prefix}{base}")
+            } else {
+                topic.to_string()
+            }
+        }
+        None => topic.to_string(),
+    }
+}
+
+/// Removes the `~` attribute and expands it in every topic attribute starting or ending with `~`,
+/// including the topics of the availability list.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+pub fn expand_base_topic(attributes: &mut Map<String, Value>) {
+    let Some(Value::String(base)) = attributes.remove("~") else {
+        return;
+    };
+    let expand = |topic: &mut Value| {
+        if let Value::String(topic) = topic {
+            *topic = resolve_topic(Some(&base), topic);
+        }
+    };
+    for (name, value) in attributes.iter_mut() {
+        if is_topic_attribute(name) {
+            expand(value);
+        }
+    }
+    for name in ["avty", "availability"] {
+        if let Some(Value::Array(checks)) = attributes.get_mut(name) {
+            for check in checks.iter_mut().filter_map(Value::as_object_mut) {
+                for (name, value) in check.iter_mut() {
+                    if is_topic_attribute(name) {
+                        expand(value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::mqtt::{
+        common::{Availability, AvailabilityCheck},
+        switch::Switch,
+    };
+
+    #[test]
+    fn can_read_a_discovery_message_with_a_node_id() {
+        let discovered = Entity::from_discovery(
+            "homeassistant/switch/gateway/irrigation/config",
+            br#"{
+                "~": "garden/irrigation",
+                "avty": [{"t": "~/availability"}],
+                "cmd_t": "~/set",
+                "state_topic": "~/state",
+                "json_attr_t": "attributes/~",
+                "uniq_id": "irrigation"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(discovered.node_id.as_deref(), Some("gateway"));
+        assert_eq!(discovered.object_id, "irrigation");
+        assert_eq!(
+            discovered.entity,
+            Entity::Switch(
+                Switch::default()
+                    .availability(Availability::single(AvailabilityCheck::topic(
+                        "garden/irrigation/availability"
+                    )))
+                    .command_topic("garden/irrigation/set")
+                    .state_topic("garden/irrigation/state")
+                    .json_attributes_topic("attributes/garden/irrigation")
+                    .unique_id("irrigation")
+            )
+        );
+    }
+
+    #[test]
+    fn can_read_a_discovery_topic_without_node_id() {
+        let discovered = Entity::from_discovery(
+            "custom/prefix/binary_sensor/door/config",
+            br#"{"stat_t": "door/state"}"#,
+        )
+        .unwrap();
+        assert_eq!(discovered.node_id, None);
+        assert_eq!(discovered.object_id, "door");
+        assert_eq!(discovered.entity.get_component_name(), "binary_sensor");
+    }
+
+    #[test]
+    fn cannot_read_invalid_discovery_messages() {
+        assert!(matches!(
+            Entity::from_discovery("homeassistant/sensor/temperature/state", b"{}"),
+            Err(DiscoveryError::InvalidTopic(_))
+        ));
+        assert!(matches!(
+            Entity::from_discovery("homeassistant/toaster/bread/config", b"{}"),
+            Err(DiscoveryError::UnknownComponent(_))
+        ));
+        assert!(matches!(
+            Entity::from_discovery("homeassistant/sensor/temperature/config", b""),
+            Err(DiscoveryError::EmptyPayload)
+        ));
+        assert!(matches!(
+            Entity::from_discovery("homeassistant/sensor/temperature/config", b"[]"),
+            Err(DiscoveryError::Payload(_))
+        ));
+    }
+
+    #[test]
+    fn base_topic_is_only_expanded_in_topics() {
+        let mut attributes = json!({"~": "base", "name": "~", "stat_t": "~/state"})
+            .as_object()
+            .unwrap()
+            .clone();
+        expand_base_topic(&mut attributes);
+        assert_eq!(
+            Value::Object(attributes),
+            json!({"name": "~", "stat_t": "base/state"})
+        );
+    }
+}
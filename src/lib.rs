@@ -1,96 +1,77 @@
 #![recursion_limit = "256"]
 
+use mqtt::common::{Device, Qos};
 use mqtt::{
     alarm_control_panel::AlarmControlPanel, binary_sensor::BinarySensor, button::Button,
     camera::Camera, climate::Climate, cover::Cover, device_tracker::DeviceTracker,
     device_trigger::DeviceTrigger, event::Event, fan::Fan, humidifier::Humidifier, image::Image,
-    lawn_mower::LawnMower, lock::Lock, number::Number, scene::Scene, select::Select,
-    sensor::Sensor, siren::Siren, switch::Switch, tag::Tag, text::Text, update::Update,
-    vacuum::Vacuum, valve::Valve, water_heater::WaterHeater,
+    lawn_mower::LawnMower, light::Light, lock::Lock, notify::Notify, number::Number, scene::Scene,
+    select::Select, sensor::Sensor, siren::Siren, switch::Switch, tag::Tag, text::Text,
+    update::Update, vacuum::Vacuum, valve::Valve, water_heater::WaterHeater,
 };
-//use serde::Serialize;
-
-use serde_json::{Value, Error};
+use serde::{de, Deserialize, Deserializer};
+use serde_json::{Error, Map, Value};
 
+#[cfg(feature = "alarm")]
+pub mod alarm;
+pub mod command;
+pub mod device_discovery;
+pub mod discovery;
 pub mod mqtt;
+pub mod publisher;
+pub mod simulation;
+pub mod state;
+pub mod style;
+#[cfg(feature = "templates")]
+pub mod template;
+pub mod transport;
+pub mod validation;
+#[cfg(feature = "yaml")]
+pub mod yaml;
+
+pub use command::{Command, CommandError, CommandRouter, EntityCommands};
+pub use device_discovery::DeviceDiscovery;
+pub use discovery::{DiscoveredEntity, DiscoveryError};
+pub use publisher::{HomeAssistantMqtt, PublishError};
+pub use state::{EntityState, StatePublisher};
+pub use style::Style;
+pub use transport::{DiscoveryTransport, Message};
+pub use validation::{Validate, ValidationError};
+
+/// Names of the supported components, as used in discovery topics.
+pub(crate) const COMPONENTS: &[&str] = &[
+    "alarm_control_panel",
+    "binary_sensor",
+    "button",
+    "camera",
+    "climate",
+    "cover",
+    "device_automation",
+    "device_tracker",
+    "event",
+    "fan",
+    "humidifier",
+    "image",
+    "lawn_mower",
+    "light",
+    "lock",
+    "notify",
+    "number",
+    "scene",
+    "select",
+    "sensor",
+    "siren",
+    "switch",
+    "tag",
+    "text",
+    "update",
+    "vacuum",
+    "valve",
+    "water_heater",
+];
 
-//const ONE_WEEK_SECONDS: u32 = 60 * 60 * 24 * 7;
-
-//#[derive(Clone)]
-//pub struct HomeAssistantMqtt {
-//    client: AsyncClient,
-//    discovery_prefix: String,
-//}
-
-//impl HomeAssistantMqtt {
-//    pub fn new<S: Into<String>>(client: AsyncClient, discovery_prefix: S) -> Self {
-//        Self {
-//            client,
-//            discovery_prefix: discovery_prefix.into(),
-//        }
-//    }
-//
-//    /// The discovery topic needs to follow a specific format:
-//    /// `<discovery_prefix>/<component>/[<node_id>/]<object_id>/config`
-//    ///
-//    /// - `<discovery_prefix>`: The Discovery Prefix defaults to homeassistant. This prefix can be changed.
-//    /// - `<component>`: One of the supported MQTT integrations, eg. binary_sensor.
-//    /// - `<node_id>` (Optional): ID of the node providing the topic, this is not used by Home Assistant but may be used to structure the MQTT topic. The ID of the node must only consist of characters from the character class [a-zA-Z0-9_-] (alphanumerics, underscore and hyphen).
-//    /// - `<object_id>`: The ID of the device. This is only to allow for separate topics for each device and is not used for the entity_id. The ID of the device must only consist of characters from the character class [a-zA-Z0-9_-] (alphanumerics, underscore and hyphen).
-//    ///
-//    /// The `<node_id>` level can be used by clients to only subscribe to their own (command) topics by using one wildcard topic like <discovery_prefix>/+/<node_id>/+/set.
-//    ///
-//    /// Best practice for entities with a unique_id is to set `<object_id>` to unique_id and omit the `<node_id>`.
-//    pub async fn publish_entity(&self, entity: Entity) -> Result<()> {
-//        let component = entity.get_component_name();
-//        let attributes = entity.get_attributes()?;
-//        let object_id = attributes
-//            .as_object()
-//            .ok_or(anyhow!("entity configuration should be an object"))?
-//            .get("uniq_id")
-//            .ok_or(anyhow!(
-//                "entity configuration should have an attribute 'uniq_id'"
-//            ))?
-//            .as_str()
-//            .ok_or(anyhow!("'uniq_id' attribute should be a string"))?;
-//        let prefix = self
-//            .discovery_prefix
-//            .strip_suffix("/")
-//            .unwrap_or(&self.discovery_prefix);
-//        let topic = format!("{prefix}/{component}/{object_id}/config");
-//        let payload = serde_json::ser::to_string(&attributes).unwrap();
-//        let props = PublishProperties {
-//            //payload_format_indicator: Some(1),
-//            message_expiry_interval: Some(ONE_WEEK_SECONDS),
-//            content_type: Some("application/json".to_string()),
-//            ..Default::default()
-//        };
-//        Ok(self
-//            .client
-//            .publish_with_properties(topic, AtLeastOnce, true, payload, props)
-//            .await?)
-//    }
-//
-//    pub async fn publish_data<S: Serialize>(
-//        &self,
-//        topic: &String,
-//        payload: &S,
-//        message_expiry_interval: Option<u32>,
-//    ) -> Result<()> {
-//        let payload = serde_json::ser::to_string(payload).unwrap();
-//        let props = PublishProperties {
-//            message_expiry_interval,
-//            content_type: Some("application/json".to_string()),
-//            ..Default::default()
-//        };
-//        Ok(self
-//            .client
-//            .publish_with_properties(topic, AtLeastOnce, true, payload, props)
-//            .await?)
-//    }
-//}
-
-#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Entity {
     AlarmControlPanel(AlarmControlPanel),
     BinarySensor(BinarySensor),
@@ -105,9 +86,9 @@ pub enum Entity {
     Humidifier(Humidifier),
     Image(Image),
     LawnMower(LawnMower),
-    //Light,
+    Light(Light),
     Lock(Lock),
-    //Notify,
+    Notify(Notify),
     Number(Number),
     Scene(Scene),
     Select(Select),
@@ -132,15 +113,15 @@ impl Entity {
             Entity::Climate(_) => "climate",
             Entity::Cover(_) => "cover",
             Entity::DeviceTracker(_) => "device_tracker",
-            Entity::DeviceTrigger(_) => "device_trigger",
+            Entity::DeviceTrigger(_) => "device_automation",
             Entity::Event(_) => "event",
             Entity::Fan(_) => "fan",
             Entity::Humidifier(_) => "humidifier",
             Entity::Image(_) => "image",
             Entity::LawnMower(_) => "lawn_mower",
-            //Entity::Light(_) => "light",
+            Entity::Light(_) => "light",
             Entity::Lock(_) => "lock",
-            //Entity::Notify(_) => "notify",
+            Entity::Notify(_) => "notify",
             Entity::Number(_) => "number",
             Entity::Scene(_) => "scene",
             Entity::Select(_) => "select",
@@ -156,6 +137,72 @@ impl Entity {
         }
     }
 
+    /// The device the entity is a part of.
+    pub fn get_device(&self) -> &Device {
+        match self {
+            Entity::AlarmControlPanel(entity) => &entity.device,
+            Entity::BinarySensor(entity) => &entity.device,
+            Entity::Button(entity) => &entity.device,
+            Entity::Camera(entity) => &entity.device,
+            Entity::Climate(entity) => &entity.device,
+            Entity::Cover(entity) => &entity.device,
+            Entity::DeviceTracker(entity) => &entity.device,
+            Entity::DeviceTrigger(entity) => &entity.device,
+            Entity::Event(entity) => &entity.device,
+            Entity::Fan(entity) => &entity.device,
+            Entity::Humidifier(entity) => &entity.device,
+            Entity::Image(entity) => &entity.device,
+            Entity::LawnMower(entity) => &entity.device,
+            Entity::Light(entity) => entity.get_device(),
+            Entity::Lock(entity) => &entity.device,
+            Entity::Notify(entity) => &entity.device,
+            Entity::Number(entity) => &entity.device,
+            Entity::Scene(entity) => &entity.device,
+            Entity::Select(entity) => &entity.device,
+            Entity::Sensor(entity) => &entity.device,
+            Entity::Siren(entity) => &entity.device,
+            Entity::Switch(entity) => &entity.device,
+            Entity::Tag(entity) => &entity.device,
+            Entity::Text(entity) => &entity.device,
+            Entity::Update(entity) => &entity.device,
+            Entity::Vacuum(entity) => &entity.device,
+            Entity::Valve(entity) => &entity.device,
+            Entity::WaterHeater(entity) => &entity.device,
+        }
+    }
+
+    /// The QoS level of the entity, if set.
+    pub fn get_qos(&self) -> Option<Qos> {
+        match self {
+            Entity::Camera(_) | Entity::Image(_) | Entity::Tag(_) => None,
+            Entity::AlarmControlPanel(entity) => entity.qos,
+            Entity::BinarySensor(entity) => entity.qos,
+            Entity::Button(entity) => entity.qos,
+            Entity::Climate(entity) => entity.qos,
+            Entity::Cover(entity) => entity.qos,
+            Entity::DeviceTracker(entity) => entity.qos,
+            Entity::DeviceTrigger(entity) => entity.qos,
+            Entity::Event(entity) => entity.qos,
+            Entity::Fan(entity) => entity.qos,
+            Entity::Humidifier(entity) => entity.qos,
+            Entity::LawnMower(entity) => entity.qos,
+            Entity::Light(entity) => entity.get_qos(),
+            Entity::Lock(entity) => entity.qos,
+            Entity::Notify(entity) => entity.qos,
+            Entity::Number(entity) => entity.qos,
+            Entity::Scene(entity) => entity.qos,
+            Entity::Select(entity) => entity.qos,
+            Entity::Sensor(entity) => entity.qos,
+            Entity::Siren(entity) => entity.qos,
+            Entity::Switch(entity) => entity.qos,
+            Entity::Text(entity) => entity.qos,
+            Entity::Update(entity) => entity.qos,
+            Entity::Vacuum(entity) => entity.qos,
+            Entity::Valve(entity) => entity.qos,
+            Entity::WaterHeater(entity) => entity.qos,
+        }
+    }
+
     pub fn get_attributes(&self) -> Result<Value, Error> {
         let attributes = match self {
             Entity::AlarmControlPanel(alarm_control_panel) => {
@@ -173,9 +220,9 @@ impl Entity {
             Entity::Humidifier(humidifier) => serde_json::to_value(humidifier)?,
             Entity::Image(image) => serde_json::to_value(image)?,
             Entity::LawnMower(lawn_mower) => serde_json::to_value(lawn_mower)?,
-            //Entity::Light(light) => serde_json::to_value(light)?,
+            Entity::Light(light) => serde_json::to_value(light)?,
             Entity::Lock(lock) => serde_json::to_value(lock)?,
-            //Entity::Notify(notify) => serde_json::to_value(notify)?,
+            Entity::Notify(notify) => serde_json::to_value(notify)?,
             Entity::Number(number) => serde_json::to_value(number)?,
             Entity::Scene(scene) => serde_json::to_value(scene)?,
             Entity::Select(select) => serde_json::to_value(select)?,
@@ -191,4 +238,223 @@ impl Entity {
         };
         Ok(attributes)
     }
+
+    /// The attributes of the configuration, with abbreviated or full names.
+    pub fn get_attributes_with(&self, style: Style) -> Result<Value, Error> {
+        Ok(style.apply(self.get_attributes()?))
+    }
+
+    /// The configuration of the entity in the `mqtt:` section of `configuration.yaml`,
+    /// for installations where discovery is disabled. Device triggers and tags are rejected.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        yaml::to_yaml(std::slice::from_ref(self))
+    }
+
+    /// Parses the attributes of a component configuration, the inverse of [`Entity::get_attributes`].
+    /// Both abbreviated and full attribute names are accepted.
+    pub fn from_attributes(component: &str, attributes: Value) -> Result<Entity, Error> {
+        let entity = match component {
+            "alarm_control_panel" => Entity::AlarmControlPanel(serde_json::from_value(attributes)?),
+            "binary_sensor" => Entity::BinarySensor(serde_json::from_value(attributes)?),
+            "button" => Entity::Button(serde_json::from_value(attributes)?),
+            "camera" => Entity::Camera(serde_json::from_value(attributes)?),
+            "climate" => Entity::Climate(serde_json::from_value(attributes)?),
+            "cover" => Entity::Cover(serde_json::from_value(attributes)?),
+            "device_automation" => Entity::DeviceTrigger(serde_json::from_value(attributes)?),
+            "device_tracker" => Entity::DeviceTracker(serde_json::from_value(attributes)?),
+            "event" => Entity::Event(serde_json::from_value(attributes)?),
+            "fan" => Entity::Fan(serde_json::from_value(attributes)?),
+            "humidifier" => Entity::Humidifier(serde_json::from_value(attributes)?),
+            "image" => Entity::Image(serde_json::from_value(attributes)?),
+            "lawn_mower" => Entity::LawnMower(serde_json::from_value(attributes)?),
+            "light" => Entity::Light(serde_json::from_value(attributes)?),
+            "lock" => Entity::Lock(serde_json::from_value(attributes)?),
+            "notify" => Entity::Notify(serde_json::from_value(attributes)?),
+            "number" => Entity::Number(serde_json::from_value(attributes)?),
+            "scene" => Entity::Scene(serde_json::from_value(attributes)?),
+            "select" => Entity::Select(serde_json::from_value(attributes)?),
+            "sensor" => Entity::Sensor(serde_json::from_value(attributes)?),
+            "siren" => Entity::Siren(serde_json::from_value(attributes)?),
+            "switch" => Entity::Switch(serde_json::from_value(attributes)?),
+            "tag" => Entity::Tag(serde_json::from_value(attributes)?),
+            "text" => Entity::Text(serde_json::from_value(attributes)?),
+            "update" => Entity::Update(serde_json::from_value(attributes)?),
+            "vacuum" => Entity::Vacuum(serde_json::from_value(attributes)?),
+            "valve" => Entity::Valve(serde_json::from_value(attributes)?),
+            "water_heater" => Entity::WaterHeater(serde_json::from_value(attributes)?),
+            _ => return Err(de::Error::unknown_variant(component, COMPONENTS)),
+        };
+        Ok(entity)
+    }
+
+    /// Reads a message received on a discovery topic `<discovery_prefix>/<component>/[<node_id>/]<object_id>/config`.
+    /// The component is taken from the topic and the `~` references of the payload are expanded.
+    pub fn from_discovery(topic: &str, payload: &[u8]) -> Result<DiscoveredEntity, DiscoveryError> {
+        let discovery_topic = discovery::parse_topic(topic)?;
+        if payload.is_empty() {
+            return Err(DiscoveryError::EmptyPayload);
+        }
+        let mut attributes: Map<String, Value> = serde_json::from_slice(payload)?;
+        discovery::expand_base_topic(&mut attributes);
+        Ok(DiscoveredEntity {
+            node_id: discovery_topic.node_id.map(str::to_string),
+            object_id: discovery_topic.object_id.to_string(),
+            entity: Entity::from_attributes(discovery_topic.component, Value::Object(attributes))?,
+        })
+    }
+}
+
+/// Deserializes a component configuration holding a `platform` attribute,
+/// as found in [device discovery payloads](https://www.home-assistant.io/integrations/mqtt/#device-discovery-payload).
+impl<'de> Deserialize<'de> for Entity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let attributes = Value::deserialize(deserializer)?;
+        let component = attributes
+            .get("platform")
+            .or_else(|| attributes.get("p"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| de::Error::missing_field("platform"))?
+            .to_string();
+        Entity::from_attributes(&component, attributes).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use super::*;
+    use mqtt::common::{AvailabilityCheck, DeviceConnection};
+    use mqtt::units::{EnergyUnit, LengthUnit, Unit};
+
+    #[test]
+    fn can_deserialize_abbreviated_and_full_attribute_names() {
+        let abbreviated = Entity::from_attributes(
+            "sensor",
+            json!({
+                "dev": {"ids": "meter-01", "cns": [["mac", "09:AF:A4:54:F0:9D"]]},
+                "avty_t": "~/availability",
+                "stat_t": "~/state",
+                "uniq_id": "meter-01_energy",
+                "unit_of_meas": "kWh",
+                "qos": 1
+            }),
+        )
+        .unwrap();
+        let full = Entity::from_attributes(
+            "sensor",
+            json!({
+                "device": {"identifiers": ["meter-01"], "connections": [["mac", "09:AF:A4:54:F0:9D"]]},
+                "availability": [{"topic": "~/availability"}],
+                "state_topic": "~/state",
+                "unique_id": "meter-01_energy",
+                "unit_of_measurement": "kWh",
+                "qos": "1"
+            }),
+        )
+        .unwrap();
+
+        let expected = Entity::Sensor(
+            Sensor::default()
+                .device(
+                    Device::default()
+                        .add_identifier("meter-01")
+                        .add_connection(DeviceConnection::mac("09:AF:A4:54:F0:9D")),
+                )
+                .availability(mqtt::common::Availability::single(
+                    AvailabilityCheck::topic("~/availability"),
+                ))
+                .state_topic("~/state")
+                .unique_id("meter-01_energy")
+                .unit_of_measurement(Unit::Energy(EnergyUnit::KiloWattHour))
+                .qos(Qos::AtLeastOnce),
+        );
+        assert_eq!(abbreviated, expected);
+        assert_eq!(full, expected);
+    }
+
+    #[test]
+    fn can_round_trip_climate_templates_read_with_their_full_names() {
+        let entity = Entity::from_attributes(
+            "climate",
+            json!({
+                "mode_state_template": "{{ value_json.mode }}",
+                "swing_mode_state_template": "{{ value_json.swing }}"
+            }),
+        )
+        .unwrap();
+        let expected = Entity::Climate(
+            Climate::default()
+                .mode_state_template("{{ value_json.mode }}")
+                .swing_mode_state_template("{{ value_json.swing }}"),
+        );
+        assert_eq!(entity, expected);
+
+        let attributes = entity.get_attributes().unwrap();
+        assert_json_eq!(
+            attributes["swing_mode_stat_tpl"],
+            json!("{{ value_json.swing }}")
+        );
+        assert_eq!(
+            Entity::from_attributes("climate", attributes).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn can_round_trip_an_entity_with_its_platform() {
+        let entity = Entity::Number(
+            Number::default()
+                .unique_id("distance")
+                .command_topic("~/set")
+                .unit_of_measurement(Unit::Length(LengthUnit::Meters)),
+        );
+        let attributes = entity.get_attributes().unwrap();
+        assert_json_eq!(attributes["platform"], json!("number"));
+        assert_eq!(
+            serde_json::from_value::<Entity>(attributes).unwrap(),
+            entity
+        );
+    }
+
+    #[test]
+    fn can_serialize_a_notify_entity() {
+        let entity: Entity = Notify::default()
+            .unique_id("status_screen")
+            .name("Status screen")
+            .command_topic("home/living_room/status_screen/notifications")
+            .command_template("{{ value | upper }}")
+            .qos(Qos::AtMostOnce)
+            .retain(false)
+            .into();
+        let attributes = entity.get_attributes().unwrap();
+        assert_json_eq!(
+            attributes,
+            json!({
+                "o": {"name": ""},
+                "dev": {},
+                "avty_mode": "all",
+                "avty": [],
+                "platform": "notify",
+                "uniq_id": "status_screen",
+                "name": "Status screen",
+                "cmd_t": "home/living_room/status_screen/notifications",
+                "cmd_tpl": "{{ value | upper }}",
+                "qos": 0,
+                "ret": false
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Entity>(attributes).unwrap(),
+            entity
+        );
+    }
+
+    #[test]
+    fn cannot_deserialize_an_unknown_component() {
+        assert!(Entity::from_attributes("toaster", json!({})).is_err());
+        assert!(serde_json::from_value::<Entity>(json!({"uniq_id": "toaster"})).is_err());
+    }
 }
@@ -1,10 +1,13 @@
 use super::common::Qos;
 use super::common::{Availability, Device, EntityCategory, Origin};
+use super::features::AlarmFeature;
 use crate::Entity;
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 
-///
-#[derive(Clone, Debug, PartialEq, Serialize)]
+/// MQTT discovery configuration of a `alarm_control_panel` entity.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/alarm_control_panel.mqtt/)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AlarmControlPanel {
     /// Replaces `~` with this value in any MQTT topic attribute.
     /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
@@ -12,11 +15,11 @@ pub struct AlarmControlPanel {
     pub topic_prefix: Option<String>,
 
     /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
-    #[serde(rename = "o")]
+    #[serde(rename = "o", alias = "origin")]
     pub origin: Origin,
 
     /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
-    #[serde(rename = "dev")]
+    #[serde(rename = "dev", alias = "device")]
     pub device: Device,
 
     /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
@@ -24,7 +27,11 @@ pub struct AlarmControlPanel {
     pub availability: Availability,
 
     /// The category of the entity. (optional, default: None)
-    #[serde(rename = "ent_cat", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_category: Option<EntityCategory>,
 
     /// If defined, specifies a code to enable or disable the alarm in the frontend. Note that the code is validated locally and blocks sending MQTT messages to the remote device. For remote code validation, the code can be configured to either of the special values `REMOTE_CODE` (numeric code) or `REMOTE_CODE_TEXT` (text code). In this case, local code validation is bypassed but the frontend will still show a numeric or text code dialog. Use `command_template` to send the code to the remote device. Example configurations for remote code validation [can be found here](#configurations-with-remote-code-validation).
@@ -32,47 +39,83 @@ pub struct AlarmControlPanel {
     pub code: Option<String>,
 
     /// If true the code is required to arm the alarm. If false the code is not validated.
-    #[serde(rename = "cod_arm_req", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "cod_arm_req",
+        alias = "code_arm_required",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub code_arm_required: Option<bool>,
 
     /// If true the code is required to disarm the alarm. If false the code is not validated.
-    #[serde(rename = "cod_dis_req", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "cod_dis_req",
+        alias = "code_disarm_required",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub code_disarm_required: Option<bool>,
 
     /// If true the code is required to trigger the alarm. If false the code is not validated.
-    #[serde(rename = "cod_trig_req", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "cod_trig_req",
+        alias = "code_trigger_required",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub code_trigger_required: Option<bool>,
 
     /// The [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) used for the command payload. Available variables: `action` and `code`.
-    #[serde(rename = "cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "cmd_tpl",
+        alias = "command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the alarm state.
-    #[serde(rename = "cmd_t")]
+    #[serde(rename = "cmd_t", alias = "command_topic")]
     pub command_topic: String,
 
     /// Flag which defines if the entity should be enabled when first added.
-    #[serde(rename = "en", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub enabled_by_default: Option<bool>,
 
     /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
-    #[serde(rename = "e", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub encoding: Option<String>,
 
     /// Picture URL for the entity.
-    #[serde(rename = "ent_pic", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_pic",
+        alias = "entity_picture",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_picture: Option<String>,
 
     /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
-    #[serde(rename = "ic", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
-    #[serde(rename = "json_attr_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_template: Option<String>,
 
     /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
-    #[serde(rename = "json_attr_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_topic: Option<String>,
 
     /// The name of the alarm. Can be set to `null` if only the device name is relevant.
@@ -80,39 +123,71 @@ pub struct AlarmControlPanel {
     pub name: Option<String>,
 
     /// Used instead of `name` for automatic generation of `entity_id`
-    #[serde(rename = "obj_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub object_id: Option<String>,
 
     /// The payload to set armed-away mode on your Alarm Panel.
-    #[serde(rename = "pl_arm_away", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_arm_away",
+        alias = "payload_arm_away",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_arm_away: Option<String>,
 
     /// The payload to set armed-home mode on your Alarm Panel.
-    #[serde(rename = "pl_arm_home", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_arm_home",
+        alias = "payload_arm_home",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_arm_home: Option<String>,
 
     /// The payload to set armed-night mode on your Alarm Panel.
-    #[serde(rename = "pl_arm_nite", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_arm_nite",
+        alias = "payload_arm_night",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_arm_night: Option<String>,
 
     /// The payload to set armed-vacation mode on your Alarm Panel.
-    #[serde(rename = "pl_arm_vacation", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_arm_vacation",
+        alias = "payload_arm_vacation",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_arm_vacation: Option<String>,
 
     /// The payload to set armed-custom-bypass mode on your Alarm Panel.
-    #[serde(rename = "pl_arm_custom_b", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_arm_custom_b",
+        alias = "payload_arm_custom_bypass",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_arm_custom_bypass: Option<String>,
 
     /// The payload to disarm your Alarm Panel.
-    #[serde(rename = "pl_disarm", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_disarm",
+        alias = "payload_disarm",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_disarm: Option<String>,
 
     /// The payload to trigger the alarm on your Alarm Panel.
-    #[serde(rename = "pl_trig", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_trig",
+        alias = "payload_trigger",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_trigger: Option<String>,
 
     /// Must be `alarm_control_panel`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
-    #[serde(rename = "platform")]
+    #[serde(rename = "platform", alias = "p")]
     pub platform: String,
 
     /// The maximum QoS level to be used when receiving and publishing messages.
@@ -120,23 +195,39 @@ pub struct AlarmControlPanel {
     pub qos: Option<Qos>,
 
     /// If the published message should have the retain flag on or not.
-    #[serde(rename = "ret", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub retain: Option<bool>,
 
     /// The MQTT topic subscribed to receive state updates. A "None" payload resets to an `unknown` state. An empty payload is ignored. Valid state payloads are: `armed_away`, `armed_custom_bypass`, `armed_home`, `armed_night`, `armed_vacation`, `arming`, `disarmed`, `disarming` `pending` and `triggered`.
-    #[serde(rename = "stat_t")]
+    #[serde(rename = "stat_t", alias = "state_topic")]
     pub state_topic: String,
 
     /// A list of features that the alarm control panel supports. The available list options are `arm_home`, `arm_away`, `arm_night`, `arm_vacation`, `arm_custom_bypass`, and `trigger`.
-    #[serde(rename = "sup_feat", skip_serializing_if = "Option::is_none")]
-    pub supported_features: Option<Vec<String>>,
+    #[serde(
+        rename = "sup_feat",
+        alias = "supported_features",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub supported_features: Option<Vec<AlarmFeature>>,
 
     /// An ID that uniquely identifies this alarm panel. If two alarm panels have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
-    #[serde(rename = "uniq_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub unique_id: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the value.
-    #[serde(rename = "val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "val_tpl",
+        alias = "value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub value_template: Option<String>,
 }
 
@@ -329,7 +420,7 @@ impl AlarmControlPanel {
     }
 
     /// A list of features that the alarm control panel supports. The available list options are `arm_home`, `arm_away`, `arm_night`, `arm_vacation`, `arm_custom_bypass`, and `trigger`.
-    pub fn supported_features<T: Into<String>>(mut self, supported_features: Vec<T>) -> Self {
+    pub fn supported_features<T: Into<AlarmFeature>>(mut self, supported_features: Vec<T>) -> Self {
         self.supported_features = Some(supported_features.into_iter().map(|v| v.into()).collect());
         self
     }
@@ -1,12 +1,18 @@
+use super::climate_modes::FanMode;
+use super::climate_modes::HvacMode;
+use super::climate_modes::PresetMode;
+use super::climate_modes::SwingMode;
 use super::common::Qos;
 use super::common::TemperatureUnit;
 use super::common::{Availability, Device, EntityCategory, Origin};
 use crate::Entity;
 pub use rust_decimal::Decimal;
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 
-///
-#[derive(Clone, Debug, PartialEq, Serialize)]
+/// MQTT discovery configuration of a `climate` entity.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/climate.mqtt/)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Climate {
     /// Replaces `~` with this value in any MQTT topic attribute.
     /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
@@ -14,11 +20,11 @@ pub struct Climate {
     pub topic_prefix: Option<String>,
 
     /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
-    #[serde(rename = "o")]
+    #[serde(rename = "o", alias = "origin")]
     pub origin: Origin,
 
     /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
-    #[serde(rename = "dev")]
+    #[serde(rename = "dev", alias = "device")]
     pub device: Device,
 
     /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
@@ -26,15 +32,27 @@ pub struct Climate {
     pub availability: Availability,
 
     /// The category of the entity. (optional, default: None)
-    #[serde(rename = "ent_cat", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_category: Option<EntityCategory>,
 
     /// A template to render the value received on the `action_topic` with.
-    #[serde(rename = "act_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "act_tpl",
+        alias = "action_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub action_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes of the current action. If this is set, the climate graph uses the value received as data source. A "None" payload resets the current action state. An empty payload is ignored. Valid action values: `off`, `heating`, `cooling`, `drying`, `idle`, `fan`.
-    #[serde(rename = "act_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "act_t",
+        alias = "action_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub action_topic: Option<String>,
 
     /// A template with which the value received on `current_humidity_topic` will be rendered.
@@ -52,63 +70,115 @@ pub struct Climate {
     pub current_humidity_topic: Option<String>,
 
     /// A template with which the value received on `current_temperature_topic` will be rendered.
-    #[serde(rename = "curr_temp_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "curr_temp_tpl",
+        alias = "current_temperature_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub current_temperature_template: Option<String>,
 
     /// The MQTT topic on which to listen for the current temperature. A `"None"` value received will reset the current temperature. Empty values (`'''`) will be ignored.
-    #[serde(rename = "curr_temp_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "curr_temp_t",
+        alias = "current_temperature_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub current_temperature_topic: Option<String>,
 
     /// Flag which defines if the entity should be enabled when first added.
-    #[serde(rename = "en", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub enabled_by_default: Option<bool>,
 
     /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
-    #[serde(rename = "e", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub encoding: Option<String>,
 
     /// Picture URL for the entity.
-    #[serde(rename = "ent_pic", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_pic",
+        alias = "entity_picture",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_picture: Option<String>,
 
     /// A template to render the value sent to the `fan_mode_command_topic` with.
-    #[serde(rename = "fan_mode_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "fan_mode_cmd_tpl",
+        alias = "fan_mode_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub fan_mode_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the fan mode.
-    #[serde(rename = "fan_mode_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "fan_mode_cmd_t",
+        alias = "fan_mode_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub fan_mode_command_topic: Option<String>,
 
     /// A template to render the value received on the `fan_mode_state_topic` with.
-    #[serde(rename = "fan_mode_stat_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "fan_mode_stat_tpl",
+        alias = "fan_mode_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub fan_mode_state_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes of the HVAC fan mode. If this is not set, the fan mode works in optimistic mode (see below). A "None" payload resets the fan mode state. An empty payload is ignored.
-    #[serde(rename = "fan_mode_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "fan_mode_stat_t",
+        alias = "fan_mode_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub fan_mode_state_topic: Option<String>,
 
     /// A list of supported fan modes.
     #[serde(rename = "fan_modes", skip_serializing_if = "Option::is_none")]
-    pub fan_modes: Option<Vec<String>>,
+    pub fan_modes: Option<Vec<FanMode>>,
 
     /// Set the initial target temperature. The default value depends on the temperature unit and will be 21° or 69.8°F.
-    #[serde(rename = "init", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "init",
+        alias = "initial",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub initial: Option<Decimal>,
 
     /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
-    #[serde(rename = "ic", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
-    #[serde(rename = "json_attr_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_template: Option<String>,
 
     /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
-    #[serde(rename = "json_attr_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_topic: Option<String>,
 
     /// The minimum target humidity percentage that can be set.
-    #[serde(rename = "max_hum", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "max_hum",
+        alias = "max_humidity",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_humidity: Option<Decimal>,
 
     /// Maximum set point available. The default value depends on the temperature unit, and will be 35°C or 95°F.
@@ -116,7 +186,11 @@ pub struct Climate {
     pub max_temp: Option<Decimal>,
 
     /// The maximum target humidity percentage that can be set.
-    #[serde(rename = "min_hum", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "min_hum",
+        alias = "min_humidity",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_humidity: Option<Decimal>,
 
     /// Minimum set point available. The default value depends on the temperature unit, and will be 7°C or 44.6°F.
@@ -124,45 +198,81 @@ pub struct Climate {
     pub min_temp: Option<Decimal>,
 
     /// A template to render the value sent to the `mode_command_topic` with.
-    #[serde(rename = "mode_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_cmd_tpl",
+        alias = "mode_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the HVAC operation mode.
-    #[serde(rename = "mode_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_cmd_t",
+        alias = "mode_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_command_topic: Option<String>,
 
     /// A template to render the value received on the `mode_state_topic` with.
-    #[serde(rename = "mode_stat_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_stat_tpl",
+        alias = "mode_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_state_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes of the HVAC operation mode. If this is not set, the operation mode works in optimistic mode (see below). A "None" payload resets to an `unknown` state. An empty payload is ignored.
-    #[serde(rename = "mode_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_stat_t",
+        alias = "mode_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_state_topic: Option<String>,
 
     /// A list of supported modes. Needs to be a subset of the default values.
     #[serde(rename = "modes", skip_serializing_if = "Option::is_none")]
-    pub modes: Option<Vec<String>>,
+    pub modes: Option<Vec<HvacMode>>,
 
     /// The name of the HVAC. Can be set to `null` if only the device name is relevant.
     #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
     /// Used instead of `name` for automatic generation of `entity_id`
-    #[serde(rename = "obj_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub object_id: Option<String>,
 
     /// Flag that defines if the climate works in optimistic mode
-    #[serde(rename = "opt", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "opt",
+        alias = "optimistic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub optimistic: Option<bool>,
 
     /// The payload sent to turn off the device.
-    #[serde(rename = "pl_off", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_off",
+        alias = "payload_off",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_off: Option<String>,
 
     /// The payload sent to turn the device on.
-    #[serde(rename = "pl_on", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_on",
+        alias = "payload_on",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_on: Option<String>,
 
+    /// Must be `climate`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    #[serde(rename = "platform", alias = "p")]
+    pub platform: String,
+
     /// A template to render the value sent to the `power_command_topic` with. The `value` parameter is the payload set for `payload_on` or `payload_off`.
     #[serde(
         rename = "power_command_template",
@@ -182,122 +292,227 @@ pub struct Climate {
     pub precision: Option<Decimal>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `preset_mode_command_topic`.
-    #[serde(rename = "pr_mode_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_mode_cmd_tpl",
+        alias = "preset_mode_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_mode_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the preset mode.
-    #[serde(rename = "pr_mode_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_mode_cmd_t",
+        alias = "preset_mode_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_mode_command_topic: Option<String>,
 
     /// The MQTT topic subscribed to receive climate speed based on presets. When preset 'none' is received or `None` the `preset_mode` will be reset.
-    #[serde(rename = "pr_mode_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_mode_stat_t",
+        alias = "preset_mode_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_mode_state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the `preset_mode` value from the payload received on `preset_mode_state_topic`.
-    #[serde(rename = "pr_mode_val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_mode_val_tpl",
+        alias = "preset_mode_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_mode_value_template: Option<String>,
 
     /// List of preset modes this climate is supporting. Common examples include `eco`, `away`, `boost`, `comfort`, `home`, `sleep` and `activity`.
-    #[serde(rename = "pr_modes", skip_serializing_if = "Option::is_none")]
-    pub preset_modes: Option<Vec<String>>,
+    #[serde(
+        rename = "pr_modes",
+        alias = "preset_modes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub preset_modes: Option<Vec<PresetMode>>,
 
     /// The maximum QoS level to be used when receiving and publishing messages.
     #[serde(rename = "qos", skip_serializing_if = "Option::is_none")]
     pub qos: Option<Qos>,
 
     /// Defines if published messages should have the retain flag set.
-    #[serde(rename = "ret", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub retain: Option<bool>,
 
     /// A template to render the value sent to the `swing_mode_command_topic` with.
-    #[serde(rename = "swing_mode_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "swing_mode_cmd_tpl",
+        alias = "swing_mode_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub swing_mode_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the swing mode.
-    #[serde(rename = "swing_mode_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "swing_mode_cmd_t",
+        alias = "swing_mode_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub swing_mode_command_topic: Option<String>,
 
     /// A template to render the value received on the `swing_mode_state_topic` with.
     #[serde(
         rename = "swing_mode_stat_tpl",
+        alias = "swing_mode_state_template",
         skip_serializing_if = "Option::is_none"
     )]
     pub swing_mode_state_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes of the HVAC swing mode. If this is not set, the swing mode works in optimistic mode (see below).
-    #[serde(rename = "swing_mode_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "swing_mode_stat_t",
+        alias = "swing_mode_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub swing_mode_state_topic: Option<String>,
 
     /// A list of supported swing modes.
     #[serde(rename = "swing_modes", skip_serializing_if = "Option::is_none")]
-    pub swing_modes: Option<Vec<String>>,
+    pub swing_modes: Option<Vec<SwingMode>>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `target_humidity_command_topic`.
-    #[serde(rename = "hum_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "hum_cmd_tpl",
+        alias = "target_humidity_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub target_humidity_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the target humidity.
-    #[serde(rename = "hum_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "hum_cmd_t",
+        alias = "target_humidity_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub target_humidity_command_topic: Option<String>,
 
     /// The MQTT topic subscribed to receive the target humidity. If this is not set, the target humidity works in optimistic mode (see below). A `"None"` value received will reset the target humidity. Empty values (`'''`) will be ignored.
-    #[serde(rename = "hum_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "hum_stat_t",
+        alias = "target_humidity_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub target_humidity_state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract a value for the climate `target_humidity` state.
-    #[serde(rename = "hum_state_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "hum_state_tpl",
+        alias = "target_humidity_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub target_humidity_state_template: Option<String>,
 
     /// A template to render the value sent to the `temperature_command_topic` with.
-    #[serde(rename = "temp_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_cmd_tpl",
+        alias = "temperature_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the target temperature.
-    #[serde(rename = "temp_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_cmd_t",
+        alias = "temperature_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_command_topic: Option<String>,
 
     /// A template to render the value sent to the `temperature_high_command_topic` with.
-    #[serde(rename = "temp_hi_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_hi_cmd_tpl",
+        alias = "temperature_high_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_high_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the high target temperature.
-    #[serde(rename = "temp_hi_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_hi_cmd_t",
+        alias = "temperature_high_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_high_command_topic: Option<String>,
 
     /// A template to render the value received on the `temperature_high_state_topic` with. A `"None"` value received will reset the temperature high set point. Empty values (`'''`) will be ignored.
-    #[serde(rename = "temp_hi_stat_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_hi_stat_tpl",
+        alias = "temperature_high_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_high_state_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes in the target high temperature. If this is not set, the target high temperature works in optimistic mode (see below).
-    #[serde(rename = "temp_hi_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_hi_stat_t",
+        alias = "temperature_high_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_high_state_topic: Option<String>,
 
     /// A template to render the value sent to the `temperature_low_command_topic` with.
-    #[serde(rename = "temp_lo_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_lo_cmd_tpl",
+        alias = "temperature_low_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_low_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the target low temperature.
-    #[serde(rename = "temp_lo_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_lo_cmd_t",
+        alias = "temperature_low_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_low_command_topic: Option<String>,
 
     /// A template to render the value received on the `temperature_low_state_topic` with. A `"None"` value received will reset the temperature low set point. Empty values (`'''`) will be ignored.
-    #[serde(rename = "temp_lo_stat_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_lo_stat_tpl",
+        alias = "temperature_low_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_low_state_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes in the target low temperature. If this is not set, the target low temperature works in optimistic mode (see below).
-    #[serde(rename = "temp_lo_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_lo_stat_t",
+        alias = "temperature_low_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_low_state_topic: Option<String>,
 
     /// A template to render the value received on the `temperature_state_topic` with.
-    #[serde(rename = "temp_stat_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_stat_tpl",
+        alias = "temperature_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_state_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes in the target temperature. If this is not set, the target temperature works in optimistic mode (see below). A `"None"` value received will reset the temperature set point. Empty values (`'''`) will be ignored.
-    #[serde(rename = "temp_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_stat_t",
+        alias = "temperature_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_state_topic: Option<String>,
 
     /// Defines the temperature unit of the device, `C` or `F`. If this is not set, the temperature unit is set to the system temperature unit.
-    #[serde(rename = "temp_unit", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_unit",
+        alias = "temperature_unit",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_unit: Option<TemperatureUnit>,
 
     /// Step size for temperature set point.
@@ -305,11 +520,19 @@ pub struct Climate {
     pub temp_step: Option<Decimal>,
 
     /// An ID that uniquely identifies this HVAC device. If two HVAC devices have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
-    #[serde(rename = "uniq_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub unique_id: Option<String>,
 
     /// Default template to render the payloads on *all* `*_state_topic`s with.
-    #[serde(rename = "val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "val_tpl",
+        alias = "value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub value_template: Option<String>,
 }
 
@@ -436,7 +659,7 @@ impl Climate {
     }
 
     /// A list of supported fan modes.
-    pub fn fan_modes<T: Into<String>>(mut self, fan_modes: Vec<T>) -> Self {
+    pub fn fan_modes<T: Into<FanMode>>(mut self, fan_modes: Vec<T>) -> Self {
         self.fan_modes = Some(fan_modes.into_iter().map(|v| v.into()).collect());
         self
     }
@@ -517,7 +740,7 @@ impl Climate {
     }
 
     /// A list of supported modes. Needs to be a subset of the default values.
-    pub fn modes<T: Into<String>>(mut self, modes: Vec<T>) -> Self {
+    pub fn modes<T: Into<HvacMode>>(mut self, modes: Vec<T>) -> Self {
         self.modes = Some(modes.into_iter().map(|v| v.into()).collect());
         self
     }
@@ -552,6 +775,12 @@ impl Climate {
         self
     }
 
+    /// Must be `climate`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    pub fn platform<T: Into<String>>(mut self, platform: T) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
     /// A template to render the value sent to the `power_command_topic` with. The `value` parameter is the payload set for `payload_on` or `payload_off`.
     pub fn power_command_template<T: Into<String>>(mut self, power_command_template: T) -> Self {
         self.power_command_template = Some(power_command_template.into());
@@ -604,7 +833,7 @@ impl Climate {
     }
 
     /// List of preset modes this climate is supporting. Common examples include `eco`, `away`, `boost`, `comfort`, `home`, `sleep` and `activity`.
-    pub fn preset_modes<T: Into<String>>(mut self, preset_modes: Vec<T>) -> Self {
+    pub fn preset_modes<T: Into<PresetMode>>(mut self, preset_modes: Vec<T>) -> Self {
         self.preset_modes = Some(preset_modes.into_iter().map(|v| v.into()).collect());
         self
     }
@@ -655,7 +884,7 @@ impl Climate {
     }
 
     /// A list of supported swing modes.
-    pub fn swing_modes<T: Into<String>>(mut self, swing_modes: Vec<T>) -> Self {
+    pub fn swing_modes<T: Into<SwingMode>>(mut self, swing_modes: Vec<T>) -> Self {
         self.swing_modes = Some(swing_modes.into_iter().map(|v| v.into()).collect());
         self
     }
@@ -866,6 +1095,7 @@ impl Default for Climate {
             optimistic: Default::default(),
             payload_off: Default::default(),
             payload_on: Default::default(),
+            platform: "climate".to_string(),
             power_command_template: Default::default(),
             power_command_topic: Default::default(),
             precision: Default::default(),
@@ -0,0 +1,163 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::string_enum::string_enum;
+
+string_enum! {
+    /// Operation modes of a climate device, listed in `modes` and received on `mode_command_topic`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum HvacMode {
+        /// The device is turned off.
+        #[serde(rename = "off")]
+        Off,
+        /// The device is set to heat to a target temperature.
+        #[serde(rename = "heat")]
+        Heat,
+        /// The device is set to cool to a target temperature.
+        #[serde(rename = "cool")]
+        Cool,
+        /// The device is set to heat or cool to a target temperature range.
+        #[serde(rename = "heat_cool")]
+        HeatCool,
+        /// The device is set to heat or cool based on a schedule, learned behavior or AI.
+        #[serde(rename = "auto")]
+        Auto,
+        /// The device is set to dry or dehumidify.
+        #[serde(rename = "dry")]
+        Dry,
+        /// The device only runs its fan, without heating or cooling.
+        #[serde(rename = "fan_only")]
+        FanOnly,
+    }
+}
+
+string_enum! {
+    /// Current actions of a climate device, published on `action_topic`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum ClimateAction {
+        /// The device is turned off.
+        #[serde(rename = "off")]
+        Off,
+        /// The device is preheating.
+        #[serde(rename = "preheating")]
+        Preheating,
+        /// The device is heating.
+        #[serde(rename = "heating")]
+        Heating,
+        /// The device is cooling.
+        #[serde(rename = "cooling")]
+        Cooling,
+        /// The device is drying.
+        #[serde(rename = "drying")]
+        Drying,
+        /// The device is idle.
+        #[serde(rename = "idle")]
+        Idle,
+        /// The device only runs its fan.
+        #[serde(rename = "fan")]
+        Fan,
+        /// The device is defrosting.
+        #[serde(rename = "defrosting")]
+        Defrosting,
+    }
+}
+
+string_enum! {
+    /// Fan modes of a climate device, listed in `fan_modes`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum FanMode {
+        #[serde(rename = "on")]
+        On,
+        #[serde(rename = "off")]
+        Off,
+        #[serde(rename = "auto")]
+        Auto,
+        #[serde(rename = "low")]
+        Low,
+        #[serde(rename = "medium")]
+        Medium,
+        #[serde(rename = "high")]
+        High,
+        #[serde(rename = "middle")]
+        Middle,
+        #[serde(rename = "focus")]
+        Focus,
+        #[serde(rename = "diffuse")]
+        Diffuse,
+        #[serde(untagged)]
+        Other(String),
+    }
+}
+
+string_enum! {
+    /// Swing modes of a climate device, listed in `swing_modes`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum SwingMode {
+        #[serde(rename = "on")]
+        On,
+        #[serde(rename = "off")]
+        Off,
+        #[serde(rename = "both")]
+        Both,
+        #[serde(rename = "vertical")]
+        Vertical,
+        #[serde(rename = "horizontal")]
+        Horizontal,
+        #[serde(untagged)]
+        Other(String),
+    }
+}
+
+string_enum! {
+    /// Preset modes of a climate device, listed in `preset_modes`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum PresetMode {
+        /// No preset is active.
+        #[serde(rename = "none")]
+        None,
+        /// The device is running an energy-saving mode.
+        #[serde(rename = "eco")]
+        Eco,
+        /// The device is in away mode.
+        #[serde(rename = "away")]
+        Away,
+        /// The device turns all valves full up.
+        #[serde(rename = "boost")]
+        Boost,
+        /// The device is in comfort mode.
+        #[serde(rename = "comfort")]
+        Comfort,
+        /// The device is in home mode.
+        #[serde(rename = "home")]
+        Home,
+        /// The device is prepared for sleep.
+        #[serde(rename = "sleep")]
+        Sleep,
+        /// The device is reacting to activity, such as movement sensors.
+        #[serde(rename = "activity")]
+        Activity,
+        #[serde(untagged)]
+        Other(String),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_read_well_known_and_other_modes() {
+        let modes: Vec<FanMode> = serde_json::from_value(json!(["auto", "turbo"])).unwrap();
+        assert_eq!(
+            modes,
+            vec![FanMode::Auto, FanMode::Other("turbo".to_string())]
+        );
+        assert_eq!(
+            serde_json::to_value(&modes).unwrap(),
+            json!(["auto", "turbo"])
+        );
+        assert_eq!("heat_cool".parse(), Ok(HvacMode::HeatCool));
+        assert!("heat-cool".parse::<HvacMode>().is_err());
+    }
+}
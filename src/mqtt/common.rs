@@ -1,9 +1,11 @@
+use serde::de::{self, Deserializer, Visitor};
 use serde::ser::SerializeSeq;
-use serde_derive::Serialize;
+use serde::Deserialize as _;
+use serde_derive::{Deserialize, Serialize};
 
 /// Classification of a non-primary entity.
 #[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum EntityCategory {
     /// The entity allows changing the configuration of a device,
     /// for example a switch entity making it possible to turn the background illumination of a switch on and off.
@@ -17,16 +19,24 @@ pub enum EntityCategory {
 }
 
 /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
-#[derive(Clone, Debug, PartialEq, Serialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct Origin {
     /// The name of the application that is the origin the discovered MQTT item. This option is required.
     #[serde(rename = "name")]
     pub name: String,
     /// Software version of the application that supplies the discovered MQTT item.
-    #[serde(rename = "sw", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "sw",
+        alias = "sw_version",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub sw_version: Option<String>,
     /// Support URL of the application that supplies the discovered MQTT item.
-    #[serde(rename = "support_url", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "support_url",
+        alias = "url",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub support_url: Option<String>,
 }
 
@@ -53,34 +63,68 @@ impl Origin {
 }
 
 /// Information about the device this sensor is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/device_registry_index/). Only works when `unique_id` is set. At least one of identifiers or connections must be present to identify the device.
-#[derive(Clone, Debug, PartialEq, Serialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct Device {
     /// The name of the device.
     #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// A list of IDs that uniquely identify the device. For example a serial number.
-    #[serde(rename = "ids", skip_serializing_if = "Vec::is_empty")]
+    #[serde(
+        rename = "ids",
+        alias = "identifiers",
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "one_or_many"
+    )]
     pub identifiers: Vec<String>,
     /// A list of connections of the device to the outside world as a list of tuples `[connection_type, connection_identifier]`. For example the MAC address of a network interface: `"connections": [["mac", "02:5b:26:a8:dc:12"]]`.
-    #[serde(rename = "cns", skip_serializing_if = "Vec::is_empty")]
+    #[serde(
+        rename = "cns",
+        alias = "connections",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub connections: Vec<DeviceConnection>,
     /// A link to the webpage that can manage the configuration of this device. Can be either an `http://`, `https://` or an internal `homeassistant://` URL.
-    #[serde(rename = "cu", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "cu",
+        alias = "configuration_url",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub configuration_url: Option<String>,
     /// The manufacturer of the device.
-    #[serde(rename = "mf", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mf",
+        alias = "manufacturer",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub manufacturer: Option<String>,
     /// The model of the device.
-    #[serde(rename = "mdl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mdl",
+        alias = "model",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub model: Option<String>,
     /// Suggest an area if the device isn’t in one yet.
-    #[serde(rename = "sa", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "sa",
+        alias = "suggested_area",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub suggested_area: Option<String>,
     /// The firmware version of the device.
-    #[serde(rename = "sw", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "sw",
+        alias = "sw_version",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub sw_version: Option<String>,
     /// The hardware version of the device.
-    #[serde(rename = "hw", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "hw",
+        alias = "hw_version",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub hw_version: Option<String>,
     /// Identifier of a device that routes messages between this device and Home Assistant. Examples of such devices are hubs, or parent devices of a sub-device. This is used to show device topology in Home Assistant.
     #[serde(rename = "via_device", skip_serializing_if = "Option::is_none")]
@@ -147,6 +191,17 @@ impl Device {
         self.via_device = Some(via_device.into());
         self
     }
+
+    /// Whether both devices share at least one identifier or connection, which is how Home Assistant ties them to the same device registry entry.
+    pub fn matches(&self, other: &Device) -> bool {
+        self.identifiers
+            .iter()
+            .any(|identifier| other.identifiers.contains(identifier))
+            || self
+                .connections
+                .iter()
+                .any(|connection| other.connections.contains(connection))
+    }
 }
 
 /// A tuple `[connection_type, connection_identifier]`.
@@ -178,8 +233,36 @@ impl serde::ser::Serialize for DeviceConnection {
     }
 }
 
+impl<'de> serde::Deserialize<'de> for DeviceConnection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (r#type, identifier) = <(String, String)>::deserialize(deserializer)?;
+        Ok(DeviceConnection { r#type, identifier })
+    }
+}
+
+/// Home Assistant accepts a single identifier as well as a list of identifiers.
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(identifier) => vec![identifier],
+        OneOrMany::Many(identifiers) => identifiers,
+    })
+}
+
 #[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum SensorStateClass {
     /// The state represents a measurement in present time, not a historical aggregation such as statistics or a prediction of the future.
     ///
@@ -204,7 +287,8 @@ pub enum SensorStateClass {
     TotalIncreasing,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[serde(from = "AvailabilityConfig")]
 pub struct Availability {
     /// Controls the conditions needed to set the entity to `available`.
     #[serde(rename = "avty_mode")]
@@ -274,8 +358,46 @@ impl Availability {
     }
 }
 
+/// Availability as found in discovery payloads, either as a list of topics or as a single `availability_topic`.
+#[derive(Deserialize)]
+struct AvailabilityConfig {
+    #[serde(rename = "avty_mode", alias = "availability_mode", default)]
+    mode: AvailabilityMode,
+    #[serde(rename = "avty", alias = "availability")]
+    availability: Option<Vec<AvailabilityCheck>>,
+    #[serde(rename = "avty_t", alias = "availability_topic")]
+    topic: Option<String>,
+    #[serde(rename = "pl_avail", alias = "payload_available")]
+    payload_available: Option<String>,
+    #[serde(rename = "pl_not_avail", alias = "payload_not_available")]
+    payload_not_available: Option<String>,
+    #[serde(rename = "avty_tpl", alias = "availability_template")]
+    value_template: Option<String>,
+    #[serde(rename = "exp_aft", alias = "expire_after")]
+    expire_after: Option<u64>,
+}
+
+impl From<AvailabilityConfig> for Availability {
+    fn from(value: AvailabilityConfig) -> Self {
+        let mut availability = value.availability.unwrap_or_default();
+        if let Some(topic) = value.topic {
+            availability.push(AvailabilityCheck {
+                payload_available: value.payload_available,
+                payload_not_available: value.payload_not_available,
+                topic,
+                value_template: value.value_template,
+            });
+        }
+        Availability {
+            mode: value.mode,
+            availability,
+            expire_after: value.expire_after,
+        }
+    }
+}
+
 #[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub enum AvailabilityMode {
     /// `payload_available` must be received on all configured availability topics before the entity is marked as online.
     #[serde(rename = "all")]
@@ -289,19 +411,31 @@ pub enum AvailabilityMode {
     Latest,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct AvailabilityCheck {
     /// The payload that represents the available state. (optional, default: `online`)
-    #[serde(rename = "pl_avail", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_avail",
+        alias = "payload_available",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_available: Option<String>,
     /// The payload that represents the unavailable state. (optional, default: `offline`)
-    #[serde(rename = "pl_not_avail", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_not_avail",
+        alias = "payload_not_available",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_not_available: Option<String>,
     /// An MQTT topic subscribed to receive availability (online/offline) updates.
-    #[serde(rename = "t")]
+    #[serde(rename = "t", alias = "topic")]
     pub topic: String,
     /// Defines a template to extract device’s availability from the topic. To determine the devices’s availability result of this template will be compared to payload_available and payload_not_available.
-    #[serde(rename = "val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "val_tpl",
+        alias = "value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub value_template: Option<String>,
 }
 
@@ -334,24 +468,103 @@ impl AvailabilityCheck {
 }
 
 /// The maximum QoS level to be used when receiving and publishing messages.
+/// Serialized as the integer level `0`, `1` or `2`.
 #[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Qos {
     /// At most once (QoS 0)
-    #[serde(rename = "0")]
     AtMostOnce,
 
     /// At least once (QoS 1)
-    #[serde(rename = "1")]
     AtLeastOnce,
 
     /// Exactly once (QoS 2)
-    #[serde(rename = "2")]
     ExactlyOnce,
 }
 
+/// A QoS level other than 0, 1 or 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidQos(pub u8);
+
+impl std::fmt::Display for InvalidQos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid QoS level {}, expected 0, 1 or 2", self.0)
+    }
+}
+
+impl std::error::Error for InvalidQos {}
+
+impl From<Qos> for u8 {
+    fn from(value: Qos) -> Self {
+        match value {
+            Qos::AtMostOnce => 0,
+            Qos::AtLeastOnce => 1,
+            Qos::ExactlyOnce => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Qos {
+    type Error = InvalidQos;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Qos::AtMostOnce),
+            1 => Ok(Qos::AtLeastOnce),
+            2 => Ok(Qos::ExactlyOnce),
+            _ => Err(InvalidQos(value)),
+        }
+    }
+}
+
+impl serde::Serialize for Qos {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8((*self).into())
+    }
+}
+
+/// Reads the integer level, or the string form `"0"`, `"1"` or `"2"` previously emitted.
+impl<'de> serde::Deserialize<'de> for Qos {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct QosVisitor;
+
+        impl Visitor<'_> for QosVisitor {
+            type Value = Qos;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a QoS level 0, 1 or 2")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Qos, E> {
+                u8::try_from(value)
+                    .ok()
+                    .and_then(|value| Qos::try_from(value).ok())
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Qos, E> {
+                u64::try_from(value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+                    .and_then(|value| self.visit_u64(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Qos, E> {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+                    .and_then(|value| self.visit_u64(value))
+            }
+        }
+
+        deserializer.deserialize_any(QosVisitor)
+    }
+}
+
 /// Defines the temperature unit of the device, `C` or `F`. If this is not set, the temperature unit is set to the system temperature unit.
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum TemperatureUnit {
     #[serde(rename = "C")]
     Celcius,
@@ -422,4 +635,40 @@ mod tests {
             serde_json::to_value(&device).unwrap()
         );
     }
+
+    #[test]
+    fn can_deserialize_device() {
+        let device = Device {
+            name: Some("device name".to_string()),
+            identifiers: vec!["device id".to_string()],
+            connections: vec![DeviceConnection::mac("connection id")],
+            configuration_url: Some("http://config.url".to_string()),
+            manufacturer: Some("device manufacturer".to_string()),
+            model: Some("device model".to_string()),
+            suggested_area: Some("area".to_string()),
+            sw_version: Some("sw_v".to_string()),
+            hw_version: Some("hw_v".to_string()),
+            via_device: Some("via".to_string()),
+        };
+        assert_eq!(
+            serde_json::from_value::<Device>(serde_json::to_value(&device).unwrap()).unwrap(),
+            device
+        );
+    }
+
+    #[test]
+    fn can_round_trip_qos_as_integer() {
+        assert_eq!(serde_json::to_value(Qos::ExactlyOnce).unwrap(), json!(2));
+        assert_eq!(
+            serde_json::from_value::<Qos>(json!(1)).unwrap(),
+            Qos::AtLeastOnce
+        );
+        assert_eq!(
+            serde_json::from_value::<Qos>(json!("0")).unwrap(),
+            Qos::AtMostOnce
+        );
+        assert!(serde_json::from_value::<Qos>(json!(3)).is_err());
+        assert_eq!(u8::from(Qos::AtLeastOnce), 1);
+        assert_eq!(Qos::try_from(3), Err(InvalidQos(3)));
+    }
 }
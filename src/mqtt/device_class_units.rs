@@ -0,0 +1,338 @@
+//! Units of measurement allowed for each device class, mirroring the `DEVICE_CLASS_UNITS` table of Home Assistant.
+//! [See Home Assistant documentation](https://developers.home-assistant.io/docs/core/entity/sensor/#available-device-classes)
+
+use super::device_classes::{NumberDeviceClass, SensorDeviceClass};
+use super::units::{
+    ConcentrationUnit, DataRateUnit, DataUnit, ElectricalUnit, EnergyUnit, FrequencyUnit,
+    IrradiationUnit, LengthUnit, LightUnit, MassUnit, PercentageUnit, PowerUnit, PrecipitationUnit,
+    PressureUnit, SignalStrengthUnit, SpeedUnit, TempUnit, TimeUnit, Unit, VoltUnit,
+    VolumeFlowRateUnit, VolumeUnit,
+};
+
+const NO_UNIT: &[Unit] = &[];
+const PERCENTAGE: &[Unit] = &[Unit::Percentage(PercentageUnit::Percentage)];
+const PARTS_PER_MILLION: &[Unit] = &[Unit::Concentration(ConcentrationUnit::PartsPerMillion)];
+const MICROGRAMS_PER_CUBIC_METER: &[Unit] = &[Unit::Concentration(
+    ConcentrationUnit::MicrogramsPerCubicMeter,
+)];
+const APPARENT_POWER: &[Unit] = &[Unit::Electrical(ElectricalUnit::VoltAmpere)];
+const CURRENT: &[Unit] = &[Unit::Electrical(ElectricalUnit::CurrentAmpere)];
+const REACTIVE_POWER: &[Unit] = &[
+    Unit::Electrical(ElectricalUnit::VoltAmpereReactive),
+    Unit::Electrical(ElectricalUnit::KiloVoltAmpereReactive),
+];
+const PRESSURE: &[Unit] = &[
+    Unit::Pressure(PressureUnit::Pa),
+    Unit::Pressure(PressureUnit::HPa),
+    Unit::Pressure(PressureUnit::Bar),
+    Unit::Pressure(PressureUnit::MBar),
+    Unit::Pressure(PressureUnit::InHg),
+    Unit::Pressure(PressureUnit::Psi),
+];
+const DATA_RATE: &[Unit] = &[
+    Unit::DataRateUnit(DataRateUnit::BitsPerSecond),
+    Unit::DataRateUnit(DataRateUnit::KilobitsPerSecond),
+    Unit::DataRateUnit(DataRateUnit::MegabitsPerSecond),
+    Unit::DataRateUnit(DataRateUnit::GigabitsPerSecond),
+    Unit::DataRateUnit(DataRateUnit::BytesPerSecond),
+    Unit::DataRateUnit(DataRateUnit::KilobytesPerSecond),
+    Unit::DataRateUnit(DataRateUnit::MegabytesPerSecond),
+    Unit::DataRateUnit(DataRateUnit::GigabytesPerSecond),
+    Unit::DataRateUnit(DataRateUnit::KibibytesPerSecond),
+    Unit::DataRateUnit(DataRateUnit::MebibytesPerSecond),
+    Unit::DataRateUnit(DataRateUnit::GibibytesPerSecond),
+];
+const DATA_SIZE: &[Unit] = &[
+    Unit::Data(DataUnit::Bits),
+    Unit::Data(DataUnit::Kilobits),
+    Unit::Data(DataUnit::Megabits),
+    Unit::Data(DataUnit::Gigabits),
+    Unit::Data(DataUnit::Bytes),
+    Unit::Data(DataUnit::Kilobytes),
+    Unit::Data(DataUnit::Megabytes),
+    Unit::Data(DataUnit::Gigabytes),
+    Unit::Data(DataUnit::Terabytes),
+    Unit::Data(DataUnit::Petabytes),
+    Unit::Data(DataUnit::Exabytes),
+    Unit::Data(DataUnit::Zettabytes),
+    Unit::Data(DataUnit::Yottabytes),
+    Unit::Data(DataUnit::Kibibytes),
+    Unit::Data(DataUnit::Mebibytes),
+    Unit::Data(DataUnit::Gibibytes),
+    Unit::Data(DataUnit::Tebibytes),
+    Unit::Data(DataUnit::Pebibytes),
+    Unit::Data(DataUnit::Exbibytes),
+    Unit::Data(DataUnit::Zebibytes),
+    Unit::Data(DataUnit::Yobibytes),
+];
+const DISTANCE: &[Unit] = &[
+    Unit::Length(LengthUnit::Millimeters),
+    Unit::Length(LengthUnit::Centimeters),
+    Unit::Length(LengthUnit::Meters),
+    Unit::Length(LengthUnit::Kilometers),
+    Unit::Length(LengthUnit::Inches),
+    Unit::Length(LengthUnit::Feet),
+    Unit::Length(LengthUnit::Yard),
+    Unit::Length(LengthUnit::Miles),
+];
+const DURATION: &[Unit] = &[
+    Unit::Time(TimeUnit::Microseconds),
+    Unit::Time(TimeUnit::Milliseconds),
+    Unit::Time(TimeUnit::Seconds),
+    Unit::Time(TimeUnit::Minutes),
+    Unit::Time(TimeUnit::Hours),
+    Unit::Time(TimeUnit::Days),
+];
+const ENERGY: &[Unit] = &[
+    Unit::Energy(EnergyUnit::WattHour),
+    Unit::Energy(EnergyUnit::KiloWattHour),
+];
+const FREQUENCY: &[Unit] = &[
+    Unit::Frequency(FrequencyUnit::Hertz),
+    Unit::Frequency(FrequencyUnit::GigaHertz),
+];
+const GAS: &[Unit] = &[
+    Unit::Volume(VolumeUnit::CubicMeters),
+    Unit::Volume(VolumeUnit::CubicFeet),
+];
+const ILLUMINANCE: &[Unit] = &[Unit::Light(LightUnit::Lux)];
+const IRRADIANCE: &[Unit] = &[Unit::Irradiation(IrradiationUnit::WattsPerSquareMeter)];
+const POWER: &[Unit] = &[
+    Unit::Power(PowerUnit::Watt),
+    Unit::Power(PowerUnit::KiloWatt),
+];
+const PRECIPITATION: &[Unit] = &[
+    Unit::Length(LengthUnit::Millimeters),
+    Unit::Length(LengthUnit::Centimeters),
+    Unit::Length(LengthUnit::Inches),
+];
+const PRECIPITATION_INTENSITY: &[Unit] = &[
+    Unit::Speed(SpeedUnit::InchesPerDay),
+    Unit::Speed(SpeedUnit::InchesPerHour),
+    Unit::Speed(SpeedUnit::MillimetersPerDay),
+    Unit::Precipitation(PrecipitationUnit::MillimetersPerHour),
+];
+const SIGNAL_STRENGTH: &[Unit] = &[
+    Unit::SignalStrength(SignalStrengthUnit::Decibels),
+    Unit::SignalStrength(SignalStrengthUnit::DecibelsMilliwatt),
+];
+const SOUND_PRESSURE: &[Unit] = &[Unit::SignalStrength(SignalStrengthUnit::Decibels)];
+const SPEED: &[Unit] = &[
+    Unit::Speed(SpeedUnit::MillimetersPerDay),
+    Unit::Speed(SpeedUnit::InchesPerDay),
+    Unit::Speed(SpeedUnit::MetersPerSecond),
+    Unit::Speed(SpeedUnit::InchesPerHour),
+    Unit::Precipitation(PrecipitationUnit::MillimetersPerHour),
+    Unit::Speed(SpeedUnit::KilometersPerHour),
+    Unit::Speed(SpeedUnit::MilesPerHour),
+];
+const TEMPERATURE: &[Unit] = &[
+    Unit::Temperature(TempUnit::Celsius),
+    Unit::Temperature(TempUnit::TempFahrenheit),
+    Unit::Temperature(TempUnit::TempKelvin),
+];
+const VOLATILE_ORGANIC_COMPOUNDS_PARTS: &[Unit] = &[
+    Unit::Concentration(ConcentrationUnit::PartsPerMillion),
+    Unit::Concentration(ConcentrationUnit::PartsPerBillion),
+];
+const VOLTAGE: &[Unit] = &[Unit::Volt(VoltUnit::Volt)];
+const VOLUME: &[Unit] = &[
+    Unit::Volume(VolumeUnit::Liters),
+    Unit::Volume(VolumeUnit::Milliliters),
+    Unit::Volume(VolumeUnit::CubicMeters),
+    Unit::Volume(VolumeUnit::CubicFeet),
+    Unit::Volume(VolumeUnit::Gallons),
+    Unit::Volume(VolumeUnit::FluidOunce),
+];
+const VOLUME_FLOW_RATE: &[Unit] = &[
+    Unit::VolumeFlowRate(VolumeFlowRateUnit::CubicMetersPerHour),
+    Unit::VolumeFlowRate(VolumeFlowRateUnit::CubicFeetPerMinute),
+];
+const WATER: &[Unit] = &[
+    Unit::Volume(VolumeUnit::Liters),
+    Unit::Volume(VolumeUnit::CubicMeters),
+    Unit::Volume(VolumeUnit::CubicFeet),
+    Unit::Volume(VolumeUnit::Gallons),
+];
+const WEIGHT: &[Unit] = &[
+    Unit::Mass(MassUnit::Grams),
+    Unit::Mass(MassUnit::Kilograms),
+    Unit::Mass(MassUnit::Milligrams),
+    Unit::Mass(MassUnit::Micrograms),
+    Unit::Mass(MassUnit::Ounces),
+    Unit::Mass(MassUnit::Pounds),
+];
+const WIND_SPEED: &[Unit] = &[
+    Unit::Speed(SpeedUnit::MetersPerSecond),
+    Unit::Speed(SpeedUnit::KilometersPerHour),
+    Unit::Speed(SpeedUnit::MilesPerHour),
+];
+
+impl SensorDeviceClass {
+    /// The units of measurement allowed with this device class, an empty list meaning that the value has no unit,
+    /// see also [`Self::allows_no_unit`]. `None` when any unit is allowed.
+    pub fn allowed_units(&self) -> Option<&'static [Unit]> {
+        let units = match self {
+            SensorDeviceClass::None | SensorDeviceClass::Monetary => return None,
+            SensorDeviceClass::Date | SensorDeviceClass::Enum | SensorDeviceClass::Timestamp => {
+                NO_UNIT
+            }
+            SensorDeviceClass::ApparentPower => APPARENT_POWER,
+            SensorDeviceClass::Aqi => NO_UNIT,
+            SensorDeviceClass::AtmosphericPressure => PRESSURE,
+            SensorDeviceClass::Battery => PERCENTAGE,
+            SensorDeviceClass::CarbonDioxide => PARTS_PER_MILLION,
+            SensorDeviceClass::CarbonMonoxide => PARTS_PER_MILLION,
+            SensorDeviceClass::Current => CURRENT,
+            SensorDeviceClass::DataRate => DATA_RATE,
+            SensorDeviceClass::DataSize => DATA_SIZE,
+            SensorDeviceClass::Distance => DISTANCE,
+            SensorDeviceClass::Duration => DURATION,
+            SensorDeviceClass::Energy => ENERGY,
+            SensorDeviceClass::EnergyStorage => ENERGY,
+            SensorDeviceClass::Frequency => FREQUENCY,
+            SensorDeviceClass::Gas => GAS,
+            SensorDeviceClass::Humidity => PERCENTAGE,
+            SensorDeviceClass::Illuminance => ILLUMINANCE,
+            SensorDeviceClass::Irradiance => IRRADIANCE,
+            SensorDeviceClass::Moisture => PERCENTAGE,
+            SensorDeviceClass::NitrogenDioxide => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::NitrogenMonoxide => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::NitrousOxide => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::Ozone => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::Ph => NO_UNIT,
+            SensorDeviceClass::Pm1 => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::Pm25 => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::Pm10 => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::PowerFactor => PERCENTAGE,
+            SensorDeviceClass::Power => POWER,
+            SensorDeviceClass::Precipitation => PRECIPITATION,
+            SensorDeviceClass::PrecipitationIntensity => PRECIPITATION_INTENSITY,
+            SensorDeviceClass::Pressure => PRESSURE,
+            // var isn't a supported unit yet
+            SensorDeviceClass::ReactivePower => REACTIVE_POWER,
+            SensorDeviceClass::SignalStrength => SIGNAL_STRENGTH,
+            SensorDeviceClass::SoundPressure => SOUND_PRESSURE,
+            SensorDeviceClass::Speed => SPEED,
+            SensorDeviceClass::SulphurDioxide => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::Temperature => TEMPERATURE,
+            SensorDeviceClass::VolatileOrganicCompounds => MICROGRAMS_PER_CUBIC_METER,
+            SensorDeviceClass::VolatileOrganicCompoundsParts => VOLATILE_ORGANIC_COMPOUNDS_PARTS,
+            SensorDeviceClass::Voltage => VOLTAGE,
+            SensorDeviceClass::Volume => VOLUME,
+            SensorDeviceClass::VolumeFlowRate => VOLUME_FLOW_RATE,
+            SensorDeviceClass::VolumeStorage => VOLUME,
+            SensorDeviceClass::Water => WATER,
+            SensorDeviceClass::Weight => WEIGHT,
+            SensorDeviceClass::WindSpeed => WIND_SPEED,
+        };
+        Some(units)
+    }
+
+    /// Whether the unit of measurement can be used with this device class.
+    pub fn allows_unit(&self, unit: &Unit) -> bool {
+        self.allowed_units()
+            .is_none_or(|units| units.contains(unit))
+    }
+
+    /// Whether the value can have no unit of measurement, such as a power factor given as a ratio.
+    pub fn allows_no_unit(&self) -> bool {
+        *self == SensorDeviceClass::PowerFactor
+            || self.allowed_units().is_none_or(<[Unit]>::is_empty)
+    }
+}
+
+impl NumberDeviceClass {
+    /// The units of measurement allowed with this device class, an empty list meaning that the value has no unit,
+    /// see also [`Self::allows_no_unit`]. `None` when any unit is allowed.
+    pub fn allowed_units(&self) -> Option<&'static [Unit]> {
+        let units = match self {
+            NumberDeviceClass::None | NumberDeviceClass::Monetary => return None,
+            NumberDeviceClass::ApparentPower => APPARENT_POWER,
+            NumberDeviceClass::Aqi => NO_UNIT,
+            NumberDeviceClass::AtmosphericPressure => PRESSURE,
+            NumberDeviceClass::Battery => PERCENTAGE,
+            NumberDeviceClass::CarbonDioxide => PARTS_PER_MILLION,
+            NumberDeviceClass::CarbonMonoxide => PARTS_PER_MILLION,
+            NumberDeviceClass::Current => CURRENT,
+            NumberDeviceClass::DataRate => DATA_RATE,
+            NumberDeviceClass::DataSize => DATA_SIZE,
+            NumberDeviceClass::Distance => DISTANCE,
+            NumberDeviceClass::Energy => ENERGY,
+            NumberDeviceClass::EnergyStorage => ENERGY,
+            NumberDeviceClass::Frequency => FREQUENCY,
+            NumberDeviceClass::Gas => GAS,
+            NumberDeviceClass::Humidity => PERCENTAGE,
+            NumberDeviceClass::Illuminance => ILLUMINANCE,
+            NumberDeviceClass::Irradiance => IRRADIANCE,
+            NumberDeviceClass::Moisture => PERCENTAGE,
+            NumberDeviceClass::NitrogenDioxide => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::NitrogenMonoxide => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::NitrousOxide => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::Ozone => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::Ph => NO_UNIT,
+            NumberDeviceClass::Pm1 => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::Pm10 => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::Pm25 => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::PowerFactor => PERCENTAGE,
+            NumberDeviceClass::Power => POWER,
+            NumberDeviceClass::Precipitation => PRECIPITATION,
+            NumberDeviceClass::PrecipitationIntensity => PRECIPITATION_INTENSITY,
+            NumberDeviceClass::Pressure => PRESSURE,
+            // var isn't a supported unit yet
+            NumberDeviceClass::ReactivePower => REACTIVE_POWER,
+            NumberDeviceClass::SignalStrength => SIGNAL_STRENGTH,
+            NumberDeviceClass::SoundPressure => SOUND_PRESSURE,
+            NumberDeviceClass::Speed => SPEED,
+            NumberDeviceClass::SulphurDioxide => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::Temperature => TEMPERATURE,
+            NumberDeviceClass::VolatileOrganicCompounds => MICROGRAMS_PER_CUBIC_METER,
+            NumberDeviceClass::Voltage => VOLTAGE,
+            NumberDeviceClass::Volume => VOLUME,
+            NumberDeviceClass::VolumeFlowRate => VOLUME_FLOW_RATE,
+            NumberDeviceClass::VolumeStorage => VOLUME,
+            NumberDeviceClass::Water => WATER,
+            NumberDeviceClass::Weight => WEIGHT,
+            NumberDeviceClass::WindSpeed => WIND_SPEED,
+        };
+        Some(units)
+    }
+
+    /// Whether the unit of measurement can be used with this device class.
+    pub fn allows_unit(&self, unit: &Unit) -> bool {
+        self.allowed_units()
+            .is_none_or(|units| units.contains(unit))
+    }
+
+    /// Whether the value can have no unit of measurement, such as a power factor given as a ratio.
+    pub fn allows_no_unit(&self) -> bool {
+        *self == NumberDeviceClass::PowerFactor
+            || self.allowed_units().is_none_or(<[Unit]>::is_empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mqtt::units::{AreaUnit, CurrencyUnit};
+
+    #[test]
+    fn device_classes_only_allow_units_of_their_quantity() {
+        assert!(SensorDeviceClass::Temperature.allows_unit(&Unit::Temperature(TempUnit::Celsius)));
+        assert!(
+            !SensorDeviceClass::Temperature.allows_unit(&Unit::Energy(EnergyUnit::KiloWattHour))
+        );
+        assert!(!SensorDeviceClass::Ph.allows_unit(&Unit::Percentage(PercentageUnit::Percentage)));
+        assert!(SensorDeviceClass::Monetary.allows_unit(&Unit::Currency(CurrencyUnit::Euro)));
+        assert!(!SensorDeviceClass::Timestamp.allows_unit(&Unit::Time(TimeUnit::Seconds)));
+        assert!(SensorDeviceClass::PowerFactor.allows_no_unit());
+        assert!(!SensorDeviceClass::Temperature.allows_no_unit());
+        assert!(SensorDeviceClass::ReactivePower
+            .allows_unit(&Unit::Electrical(ElectricalUnit::KiloVoltAmpereReactive)));
+        assert!(!NumberDeviceClass::ReactivePower.allows_no_unit());
+        assert!(SensorDeviceClass::Speed
+            .allows_unit(&Unit::Precipitation(PrecipitationUnit::MillimetersPerHour)));
+        assert!(NumberDeviceClass::Distance.allows_unit(&Unit::Length(LengthUnit::Meters)));
+        assert!(!NumberDeviceClass::Distance.allows_unit(&Unit::Area(AreaUnit::SquareMeters)));
+    }
+}
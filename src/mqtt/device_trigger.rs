@@ -1,10 +1,12 @@
 use super::common::Qos;
 use super::common::{Availability, Device, EntityCategory, Origin};
 use crate::Entity;
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 
-///
-#[derive(Clone, Debug, PartialEq, Serialize)]
+/// MQTT discovery configuration of a `device_trigger` entity.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/device_trigger.mqtt/)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DeviceTrigger {
     /// Replaces `~` with this value in any MQTT topic attribute.
     /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
@@ -12,11 +14,11 @@ pub struct DeviceTrigger {
     pub topic_prefix: Option<String>,
 
     /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
-    #[serde(rename = "o")]
+    #[serde(rename = "o", alias = "origin")]
     pub origin: Origin,
 
     /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
-    #[serde(rename = "dev")]
+    #[serde(rename = "dev", alias = "device")]
     pub device: Device,
 
     /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
@@ -24,19 +26,27 @@ pub struct DeviceTrigger {
     pub availability: Availability,
 
     /// The category of the entity. (optional, default: None)
-    #[serde(rename = "ent_cat", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_category: Option<EntityCategory>,
 
     /// The type of automation, must be 'trigger'.
-    #[serde(rename = "atype")]
+    #[serde(rename = "atype", alias = "automation_type")]
     pub automation_type: String,
 
     /// Optional payload to match the payload being sent over the topic.
-    #[serde(rename = "pl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl",
+        alias = "payload",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload: Option<String>,
 
     /// Must be `device_automation`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
-    #[serde(rename = "platform")]
+    #[serde(rename = "platform", alias = "p")]
     pub platform: String,
 
     /// The maximum QoS level to be used when receiving and publishing messages.
@@ -44,7 +54,7 @@ pub struct DeviceTrigger {
     pub qos: Option<Qos>,
 
     /// The MQTT topic subscribed to receive trigger events.
-    #[serde(rename = "t")]
+    #[serde(rename = "t", alias = "topic")]
     pub topic: String,
 
     /// The type of the trigger, e.g. `button_short_press`. Entries supported by the frontend: `button_short_press`, `button_short_release`, `button_long_press`, `button_long_release`, `button_double_press`, `button_triple_press`, `button_quadruple_press`, `button_quintuple_press`. If set to an unsupported value, will render as `subtype type`, e.g. `button_1 spammed` with `type` set to `spammed` and `subtype` set to `button_1`
@@ -52,11 +62,15 @@ pub struct DeviceTrigger {
     pub r#type: String,
 
     /// The subtype of the trigger, e.g. `button_1`. Entries supported by the frontend: `turn_on`, `turn_off`, `button_1`, `button_2`, `button_3`, `button_4`, `button_5`, `button_6`. If set to an unsupported value, will render as `subtype type`, e.g. `left_button pressed` with `type` set to `button_short_press` and `subtype` set to `left_button`
-    #[serde(rename = "stype")]
+    #[serde(rename = "stype", alias = "subtype")]
     pub subtype: String,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the value.
-    #[serde(rename = "val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "val_tpl",
+        alias = "value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub value_template: Option<String>,
 }
 
@@ -151,7 +165,7 @@ impl Default for DeviceTrigger {
             availability: Default::default(),
             automation_type: Default::default(),
             payload: Default::default(),
-            platform: "device_trigger".to_string(),
+            platform: "device_automation".to_string(),
             qos: Default::default(),
             topic: Default::default(),
             r#type: Default::default(),
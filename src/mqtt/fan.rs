@@ -1,10 +1,12 @@
 use super::common::Qos;
 use super::common::{Availability, Device, EntityCategory, Origin};
 use crate::Entity;
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 
-///
-#[derive(Clone, Debug, PartialEq, Serialize)]
+/// MQTT discovery configuration of a `fan` entity.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/fan.mqtt/)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Fan {
     /// Replaces `~` with this value in any MQTT topic attribute.
     /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
@@ -12,11 +14,11 @@ pub struct Fan {
     pub topic_prefix: Option<String>,
 
     /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
-    #[serde(rename = "o")]
+    #[serde(rename = "o", alias = "origin")]
     pub origin: Origin,
 
     /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
-    #[serde(rename = "dev")]
+    #[serde(rename = "dev", alias = "device")]
     pub device: Device,
 
     /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
@@ -24,39 +26,67 @@ pub struct Fan {
     pub availability: Availability,
 
     /// The category of the entity. (optional, default: None)
-    #[serde(rename = "ent_cat", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_category: Option<EntityCategory>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `command_topic`.
-    #[serde(rename = "cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "cmd_tpl",
+        alias = "command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the fan state.
-    #[serde(rename = "cmd_t")]
+    #[serde(rename = "cmd_t", alias = "command_topic")]
     pub command_topic: String,
 
     /// Flag which defines if the entity should be enabled when first added.
-    #[serde(rename = "en", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub enabled_by_default: Option<bool>,
 
     /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
-    #[serde(rename = "e", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub encoding: Option<String>,
 
     /// Picture URL for the entity.
-    #[serde(rename = "ent_pic", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_pic",
+        alias = "entity_picture",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_picture: Option<String>,
 
     /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
-    #[serde(rename = "ic", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
-    #[serde(rename = "json_attr_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_template: Option<String>,
 
     /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
-    #[serde(rename = "json_attr_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_topic: Option<String>,
 
     /// The name of the fan. Can be set to `null` if only the device name is relevant.
@@ -64,107 +94,207 @@ pub struct Fan {
     pub name: Option<String>,
 
     /// Used instead of `name` for automatic generation of `entity_id`
-    #[serde(rename = "obj_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub object_id: Option<String>,
 
     /// Flag that defines if fan works in optimistic mode
-    #[serde(rename = "opt", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "opt",
+        alias = "optimistic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub optimistic: Option<bool>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `direction_command_topic`.
-    #[serde(rename = "dir_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "dir_cmd_tpl",
+        alias = "direction_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub direction_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the direction state.
-    #[serde(rename = "dir_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "dir_cmd_t",
+        alias = "direction_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub direction_command_topic: Option<String>,
 
     /// The MQTT topic subscribed to receive direction state updates.
-    #[serde(rename = "dir_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "dir_stat_t",
+        alias = "direction_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub direction_state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract a value from the direction.
-    #[serde(rename = "dir_val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "dir_val_tpl",
+        alias = "direction_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub direction_value_template: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `oscillation_command_topic`.
-    #[serde(rename = "osc_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "osc_cmd_tpl",
+        alias = "oscillation_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub oscillation_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the oscillation state.
-    #[serde(rename = "osc_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "osc_cmd_t",
+        alias = "oscillation_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub oscillation_command_topic: Option<String>,
 
     /// The MQTT topic subscribed to receive oscillation state updates.
-    #[serde(rename = "osc_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "osc_stat_t",
+        alias = "oscillation_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub oscillation_state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract a value from the oscillation.
-    #[serde(rename = "osc_val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "osc_val_tpl",
+        alias = "oscillation_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub oscillation_value_template: Option<String>,
 
     /// The payload that represents the stop state.
-    #[serde(rename = "pl_off", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_off",
+        alias = "payload_off",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_off: Option<String>,
 
     /// The payload that represents the running state.
-    #[serde(rename = "pl_on", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_on",
+        alias = "payload_on",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_on: Option<String>,
 
     /// The payload that represents the oscillation off state.
-    #[serde(rename = "pl_osc_off", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_osc_off",
+        alias = "payload_oscillation_off",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_oscillation_off: Option<String>,
 
     /// The payload that represents the oscillation on state.
-    #[serde(rename = "pl_osc_on", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_osc_on",
+        alias = "payload_oscillation_on",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_oscillation_on: Option<String>,
 
     /// A special payload that resets the `percentage` state attribute to `unknown` when received at the `percentage_state_topic`.
-    #[serde(rename = "pl_rst_pct", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_rst_pct",
+        alias = "payload_reset_percentage",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_reset_percentage: Option<String>,
 
     /// A special payload that resets the `preset_mode` state attribute to `unknown` when received at the `preset_mode_state_topic`.
-    #[serde(rename = "pl_rst_pr_mode", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_rst_pr_mode",
+        alias = "payload_reset_preset_mode",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_reset_preset_mode: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `percentage_command_topic`.
-    #[serde(rename = "pct_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pct_cmd_tpl",
+        alias = "percentage_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub percentage_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the fan speed state based on a percentage.
-    #[serde(rename = "pct_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pct_cmd_t",
+        alias = "percentage_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub percentage_command_topic: Option<String>,
 
     /// The MQTT topic subscribed to receive fan speed based on percentage.
-    #[serde(rename = "pct_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pct_stat_t",
+        alias = "percentage_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub percentage_state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the `percentage` value from the payload received on `percentage_state_topic`.
-    #[serde(rename = "pct_val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pct_val_tpl",
+        alias = "percentage_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub percentage_value_template: Option<String>,
 
     /// Must be `fan`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
-    #[serde(rename = "platform")]
+    #[serde(rename = "platform", alias = "p")]
     pub platform: String,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `preset_mode_command_topic`.
-    #[serde(rename = "pr_mode_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_mode_cmd_tpl",
+        alias = "preset_mode_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_mode_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the preset mode.
-    #[serde(rename = "pr_mode_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_mode_cmd_t",
+        alias = "preset_mode_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_mode_command_topic: Option<String>,
 
     /// The MQTT topic subscribed to receive fan speed based on presets.
-    #[serde(rename = "pr_mode_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_mode_stat_t",
+        alias = "preset_mode_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_mode_state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the `preset_mode` value from the payload received on `preset_mode_state_topic`.
-    #[serde(rename = "pr_mode_val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_mode_val_tpl",
+        alias = "preset_mode_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_mode_value_template: Option<String>,
 
     /// List of preset modes this fan is capable of running at. Common examples include `auto`, `smart`, `whoosh`, `eco` and `breeze`.
-    #[serde(rename = "pr_modes", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pr_modes",
+        alias = "preset_modes",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub preset_modes: Option<Vec<String>>,
 
     /// The maximum QoS level to be used when receiving and publishing messages.
@@ -172,27 +302,51 @@ pub struct Fan {
     pub qos: Option<Qos>,
 
     /// If the published message should have the retain flag on or not.
-    #[serde(rename = "ret", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub retain: Option<bool>,
 
     /// The maximum of numeric output range (representing 100 %). The number of speeds within the `speed_range` / `100` will determine the `percentage_step`.
-    #[serde(rename = "spd_rng_max", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "spd_rng_max",
+        alias = "speed_range_max",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub speed_range_max: Option<i32>,
 
     /// The minimum of numeric output range (`off` not included, so `speed_range_min` - `1` represents 0 %). The number of speeds within the speed_range / 100 will determine the `percentage_step`.
-    #[serde(rename = "spd_rng_min", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "spd_rng_min",
+        alias = "speed_range_min",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub speed_range_min: Option<i32>,
 
     /// The MQTT topic subscribed to receive state updates. A "None" payload resets to an `unknown` state. An empty payload is ignored. By default, valid state payloads are `OFF` and `ON`. The accepted payloads can be overridden with the `payload_off` and `payload_on` config options.
-    #[serde(rename = "stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_t",
+        alias = "state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract a value from the state.
-    #[serde(rename = "stat_val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_val_tpl",
+        alias = "state_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_value_template: Option<String>,
 
     /// An ID that uniquely identifies this fan. If two fans have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
-    #[serde(rename = "uniq_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub unique_id: Option<String>,
 }
 
@@ -0,0 +1,61 @@
+use serde_derive::{Deserialize, Serialize};
+
+use super::string_enum::string_enum;
+
+string_enum! {
+    /// Features of an alarm control panel, listed in `supported_features`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum AlarmFeature {
+        #[serde(rename = "arm_home")]
+        ArmHome,
+        #[serde(rename = "arm_away")]
+        ArmAway,
+        #[serde(rename = "arm_night")]
+        ArmNight,
+        #[serde(rename = "arm_vacation")]
+        ArmVacation,
+        #[serde(rename = "arm_custom_bypass")]
+        ArmCustomBypass,
+        #[serde(rename = "trigger")]
+        Trigger,
+    }
+}
+
+string_enum! {
+    /// Features of a vacuum, listed in `supported_features`.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum VacuumFeature {
+        #[serde(rename = "start")]
+        Start,
+        #[serde(rename = "stop")]
+        Stop,
+        #[serde(rename = "pause")]
+        Pause,
+        #[serde(rename = "return_home")]
+        ReturnHome,
+        #[serde(rename = "battery")]
+        Battery,
+        #[serde(rename = "status")]
+        Status,
+        #[serde(rename = "locate")]
+        Locate,
+        #[serde(rename = "clean_spot")]
+        CleanSpot,
+        #[serde(rename = "fan_speed")]
+        FanSpeed,
+        #[serde(rename = "send_command")]
+        SendCommand,
+    }
+}
+
+impl VacuumFeature {
+    /// The features of a vacuum without `supported_features`.
+    pub const DEFAULT: &'static [VacuumFeature] = &[
+        VacuumFeature::Start,
+        VacuumFeature::Stop,
+        VacuumFeature::ReturnHome,
+        VacuumFeature::Status,
+        VacuumFeature::Battery,
+        VacuumFeature::CleanSpot,
+    ];
+}
@@ -2,10 +2,12 @@ use super::common::Qos;
 use super::common::{Availability, Device, EntityCategory, Origin};
 use crate::Entity;
 pub use rust_decimal::Decimal;
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 
-///
-#[derive(Clone, Debug, PartialEq, Serialize)]
+/// MQTT discovery configuration of a `humidifier` entity.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/humidifier.mqtt/)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Humidifier {
     /// Replaces `~` with this value in any MQTT topic attribute.
     /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
@@ -13,11 +15,11 @@ pub struct Humidifier {
     pub topic_prefix: Option<String>,
 
     /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
-    #[serde(rename = "o")]
+    #[serde(rename = "o", alias = "origin")]
     pub origin: Origin,
 
     /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
-    #[serde(rename = "dev")]
+    #[serde(rename = "dev", alias = "device")]
     pub device: Device,
 
     /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
@@ -25,15 +27,27 @@ pub struct Humidifier {
     pub availability: Availability,
 
     /// The category of the entity. (optional, default: None)
-    #[serde(rename = "ent_cat", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_category: Option<EntityCategory>,
 
     /// A template to render the value received on the `action_topic` with.
-    #[serde(rename = "act_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "act_tpl",
+        alias = "action_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub action_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes of the current action. Valid values: `off`, `humidifying`, `drying`, `idle`
-    #[serde(rename = "act_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "act_t",
+        alias = "action_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub action_topic: Option<String>,
 
     /// A template with which the value received on `current_humidity_topic` will be rendered.
@@ -51,47 +65,83 @@ pub struct Humidifier {
     pub current_humidity_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `command_topic`.
-    #[serde(rename = "cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "cmd_tpl",
+        alias = "command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the humidifier state.
-    #[serde(rename = "cmd_t")]
+    #[serde(rename = "cmd_t", alias = "command_topic")]
     pub command_topic: String,
 
     /// The device class of the MQTT device. Must be either `humidifier`, `dehumidifier` or `null`.
-    #[serde(rename = "dev_cla", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "dev_cla",
+        alias = "device_class",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub device_class: Option<String>,
 
     /// Flag which defines if the entity should be enabled when first added.
-    #[serde(rename = "en", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub enabled_by_default: Option<bool>,
 
     /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
-    #[serde(rename = "e", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub encoding: Option<String>,
 
     /// Picture URL for the entity.
-    #[serde(rename = "ent_pic", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_pic",
+        alias = "entity_picture",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_picture: Option<String>,
 
     /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
-    #[serde(rename = "ic", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
-    #[serde(rename = "json_attr_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_template: Option<String>,
 
     /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
-    #[serde(rename = "json_attr_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_topic: Option<String>,
 
     /// The minimum target humidity percentage that can be set.
-    #[serde(rename = "max_hum", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "max_hum",
+        alias = "max_humidity",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub max_humidity: Option<Decimal>,
 
     /// The maximum target humidity percentage that can be set.
-    #[serde(rename = "min_hum", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "min_hum",
+        alias = "min_humidity",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub min_humidity: Option<Decimal>,
 
     /// The name of the humidifier. Can be set to `null` if only the device name is relevant.
@@ -99,59 +149,111 @@ pub struct Humidifier {
     pub name: Option<String>,
 
     /// Used instead of `name` for automatic generation of `entity_id`
-    #[serde(rename = "obj_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub object_id: Option<String>,
 
     /// Flag that defines if humidifier works in optimistic mode
-    #[serde(rename = "opt", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "opt",
+        alias = "optimistic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub optimistic: Option<bool>,
 
     /// The payload that represents the stop state.
-    #[serde(rename = "pl_off", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_off",
+        alias = "payload_off",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_off: Option<String>,
 
     /// The payload that represents the running state.
-    #[serde(rename = "pl_on", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_on",
+        alias = "payload_on",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_on: Option<String>,
 
     /// A special payload that resets the `target_humidity` state attribute to an `unknown` state when received at the `target_humidity_state_topic`. When received at `current_humidity_topic` it will reset the current humidity state.
-    #[serde(rename = "pl_rst_hum", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_rst_hum",
+        alias = "payload_reset_humidity",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_reset_humidity: Option<String>,
 
     /// A special payload that resets the `mode` state attribute to an `unknown` state when received at the `mode_state_topic`.
-    #[serde(rename = "pl_rst_mode", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_rst_mode",
+        alias = "payload_reset_mode",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_reset_mode: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `target_humidity_command_topic`.
-    #[serde(rename = "hum_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "hum_cmd_tpl",
+        alias = "target_humidity_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub target_humidity_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the humidifier target humidity state based on a percentage.
-    #[serde(rename = "hum_cmd_t")]
+    #[serde(rename = "hum_cmd_t", alias = "target_humidity_command_topic")]
     pub target_humidity_command_topic: String,
 
     /// The MQTT topic subscribed to receive humidifier target humidity.
-    #[serde(rename = "hum_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "hum_stat_t",
+        alias = "target_humidity_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub target_humidity_state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract a value for the humidifier `target_humidity` state.
-    #[serde(rename = "hum_state_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "hum_state_tpl",
+        alias = "target_humidity_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub target_humidity_state_template: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `mode_command_topic`.
-    #[serde(rename = "mode_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_cmd_tpl",
+        alias = "mode_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the `mode` on the humidifier. This attribute ust be configured together with the `modes` attribute.
-    #[serde(rename = "mode_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_cmd_t",
+        alias = "mode_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_command_topic: Option<String>,
 
     /// The MQTT topic subscribed to receive the humidifier `mode`.
-    #[serde(rename = "mode_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_stat_t",
+        alias = "mode_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract a value for the humidifier `mode` state.
-    #[serde(rename = "mode_stat_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_stat_tpl",
+        alias = "mode_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_state_template: Option<String>,
 
     /// List of available modes this humidifier is capable of running at. Common examples include `normal`, `eco`, `away`, `boost`, `comfort`, `home`, `sleep`, `auto` and `baby`. These examples offer built-in translations but other custom modes are allowed as well.  This attribute ust be configured together with the `mode_command_topic` attribute.
@@ -159,7 +261,7 @@ pub struct Humidifier {
     pub modes: Option<Vec<String>>,
 
     /// Must be `humidifier`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
-    #[serde(rename = "platform")]
+    #[serde(rename = "platform", alias = "p")]
     pub platform: String,
 
     /// The maximum QoS level to be used when receiving and publishing messages.
@@ -167,19 +269,35 @@ pub struct Humidifier {
     pub qos: Option<Qos>,
 
     /// If the published message should have the retain flag on or not.
-    #[serde(rename = "ret", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub retain: Option<bool>,
 
     /// The MQTT topic subscribed to receive state updates. A "None" payload resets to an `unknown` state. An empty payload is ignored. Valid state payloads are `OFF` and `ON`. Custom `OFF` and `ON` values can be set with the `payload_off` and `payload_on` config options.
-    #[serde(rename = "stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_t",
+        alias = "state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_topic: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract a value from the state.
-    #[serde(rename = "stat_val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_val_tpl",
+        alias = "state_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_value_template: Option<String>,
 
     /// An ID that uniquely identifies this humidifier. If two humidifiers have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
-    #[serde(rename = "uniq_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub unique_id: Option<String>,
 }
 
@@ -0,0 +1,2064 @@
+use super::common::Qos;
+use super::common::{Availability, Device, EntityCategory, Origin};
+use crate::Entity;
+use serde::de::{self, Deserializer};
+use serde::Serializer;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The schema of a light, selecting which configuration options and payloads are used.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/light.mqtt/#comparison-of-light-mqtt-schemas)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LightSchema {
+    /// The default schema, with a topic per color attribute.
+    #[default]
+    #[serde(rename = "basic", alias = "default")]
+    Basic,
+
+    /// The state and commands are JSON payloads holding all the attributes.
+    #[serde(rename = "json")]
+    Json,
+
+    /// The state and commands are rendered and parsed with templates.
+    #[serde(rename = "template")]
+    Template,
+}
+
+/// A color mode supported by a light.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/light/#color-modes)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorMode {
+    /// The light can be turned on or off, without brightness.
+    #[serde(rename = "onoff")]
+    OnOff,
+
+    /// The light can be dimmed.
+    #[serde(rename = "brightness")]
+    Brightness,
+
+    /// The light can be dimmed and its color temperature changed.
+    #[serde(rename = "color_temp")]
+    ColorTemp,
+
+    /// The light can be dimmed and its color changed with hue and saturation.
+    #[serde(rename = "hs")]
+    Hs,
+
+    /// The light can be dimmed and its color changed with CIE 1931 xy coordinates.
+    #[serde(rename = "xy")]
+    Xy,
+
+    /// The light can be dimmed and its color changed with red, green and blue channels.
+    #[serde(rename = "rgb")]
+    Rgb,
+
+    /// The light can be dimmed and its color changed with red, green, blue and white channels.
+    #[serde(rename = "rgbw")]
+    Rgbw,
+
+    /// The light can be dimmed and its color changed with red, green, blue, cold white and warm white channels.
+    #[serde(rename = "rgbww")]
+    Rgbww,
+
+    /// The light can be switched to a white mode.
+    #[serde(rename = "white")]
+    White,
+}
+
+/// MQTT discovery configuration of a `light` entity, in one of the three schemas.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/light.mqtt/)
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Light {
+    Basic(BasicLight),
+    Json(JsonLight),
+    Template(TemplateLight),
+}
+
+impl Light {
+    /// The schema of the light.
+    pub fn schema(&self) -> LightSchema {
+        match self {
+            Light::Basic(_) => LightSchema::Basic,
+            Light::Json(_) => LightSchema::Json,
+            Light::Template(_) => LightSchema::Template,
+        }
+    }
+
+    /// The device the light is a part of.
+    pub fn get_device(&self) -> &Device {
+        match self {
+            Light::Basic(light) => &light.device,
+            Light::Json(light) => &light.device,
+            Light::Template(light) => &light.device,
+        }
+    }
+
+    /// The QoS level of the light, if set.
+    pub fn get_qos(&self) -> Option<Qos> {
+        match self {
+            Light::Basic(light) => light.qos,
+            Light::Json(light) => light.qos,
+            Light::Template(light) => light.qos,
+        }
+    }
+}
+
+impl serde::Serialize for Light {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Light::Basic(light) => light.serialize(serializer),
+            Light::Json(light) => light.serialize(serializer),
+            Light::Template(light) => light.serialize(serializer),
+        }
+    }
+}
+
+/// Reads the `schema` attribute to select the configuration, a missing schema being the default one.
+impl<'de> serde::Deserialize<'de> for Light {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let attributes = <Value as serde::Deserialize>::deserialize(deserializer)?;
+        let schema = match attributes.get("schema") {
+            Some(schema) => <LightSchema as serde::Deserialize>::deserialize(schema)
+                .map_err(de::Error::custom)?,
+            None => LightSchema::Basic,
+        };
+        let light = match schema {
+            LightSchema::Basic => {
+                Light::Basic(serde_json::from_value(attributes).map_err(de::Error::custom)?)
+            }
+            LightSchema::Json => {
+                Light::Json(serde_json::from_value(attributes).map_err(de::Error::custom)?)
+            }
+            LightSchema::Template => {
+                Light::Template(serde_json::from_value(attributes).map_err(de::Error::custom)?)
+            }
+        };
+        Ok(light)
+    }
+}
+
+impl From<Light> for Entity {
+    fn from(value: Light) -> Self {
+        Entity::Light(value)
+    }
+}
+
+/// MQTT discovery configuration of a `light` entity using the default schema.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/light.mqtt/#default-schema)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicLight {
+    /// Replaces `~` with this value in any MQTT topic attribute.
+    /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+    #[serde(rename = "~", skip_serializing_if = "Option::is_none")]
+    pub topic_prefix: Option<String>,
+
+    /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
+    #[serde(rename = "o", alias = "origin")]
+    pub origin: Origin,
+
+    /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
+    #[serde(rename = "dev", alias = "device")]
+    pub device: Device,
+
+    /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
+    #[serde(flatten)]
+    pub availability: Availability,
+
+    /// The category of the entity. (optional, default: None)
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_category: Option<EntityCategory>,
+
+    /// The MQTT topic to publish commands to change the light’s brightness.
+    #[serde(
+        rename = "bri_cmd_t",
+        alias = "brightness_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub brightness_command_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `brightness_command_topic`. Available variables: `value`.
+    #[serde(
+        rename = "bri_cmd_tpl",
+        alias = "brightness_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub brightness_command_template: Option<String>,
+
+    /// Defines the maximum brightness value (i.e., 100%) of the MQTT device.
+    #[serde(
+        rename = "bri_scl",
+        alias = "brightness_scale",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub brightness_scale: Option<i32>,
+
+    /// The MQTT topic subscribed to receive brightness state updates.
+    #[serde(
+        rename = "bri_stat_t",
+        alias = "brightness_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub brightness_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the brightness value.
+    #[serde(
+        rename = "bri_val_tpl",
+        alias = "brightness_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub brightness_value_template: Option<String>,
+
+    /// The MQTT topic subscribed to receive color mode updates. If this is not configured, `color_mode` will be automatically set according to the last received valid color or color temperature
+    #[serde(
+        rename = "clrm_stat_t",
+        alias = "color_mode_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_mode_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the color mode.
+    #[serde(
+        rename = "clrm_val_tpl",
+        alias = "color_mode_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_mode_value_template: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `color_temp_command_topic`. Available variables: `value`.
+    #[serde(
+        rename = "clr_temp_cmd_tpl",
+        alias = "color_temp_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_temp_command_template: Option<String>,
+
+    /// The MQTT topic to publish commands to change the light’s color temperature state. The color temperature command slider has a range of 153 to 500 mireds (micro reciprocal degrees).
+    #[serde(
+        rename = "clr_temp_cmd_t",
+        alias = "color_temp_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_temp_command_topic: Option<String>,
+
+    /// The MQTT topic subscribed to receive color temperature state updates.
+    #[serde(
+        rename = "clr_temp_stat_t",
+        alias = "color_temp_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_temp_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the color temperature value.
+    #[serde(
+        rename = "clr_temp_val_tpl",
+        alias = "color_temp_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_temp_value_template: Option<String>,
+
+    /// The MQTT topic to publish commands to change the switch state.
+    #[serde(rename = "cmd_t", alias = "command_topic")]
+    pub command_topic: String,
+
+    /// Flag which defines if the entity should be enabled when first added.
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enabled_by_default: Option<bool>,
+
+    /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encoding: Option<String>,
+
+    /// Picture URL for the entity.
+    #[serde(
+        rename = "ent_pic",
+        alias = "entity_picture",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_picture: Option<String>,
+
+    /// The MQTT topic to publish commands to change the light's effect state.
+    #[serde(
+        rename = "fx_cmd_t",
+        alias = "effect_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effect_command_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `effect_command_topic`. Available variables: `value`.
+    #[serde(
+        rename = "fx_cmd_tpl",
+        alias = "effect_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effect_command_template: Option<String>,
+
+    /// The list of effects the light supports.
+    #[serde(
+        rename = "fx_list",
+        alias = "effect_list",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effect_list: Option<Vec<String>>,
+
+    /// The MQTT topic subscribed to receive effect state updates.
+    #[serde(
+        rename = "fx_stat_t",
+        alias = "effect_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effect_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the effect value.
+    #[serde(
+        rename = "fx_val_tpl",
+        alias = "effect_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effect_value_template: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `hs_command_topic`. Available variables: `hue` and `sat`.
+    #[serde(
+        rename = "hs_cmd_tpl",
+        alias = "hs_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hs_command_template: Option<String>,
+
+    /// The MQTT topic to publish commands to change the light's color state in HS format (Hue Saturation). Range for Hue: 0° .. 360°, Range of Saturation: 0..100. Note: Brightness is sent separately in the `brightness_command_topic`.
+    #[serde(
+        rename = "hs_cmd_t",
+        alias = "hs_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hs_command_topic: Option<String>,
+
+    /// The MQTT topic subscribed to receive color state updates in HS format. The expected payload is the hue and saturation values separated by commas, for example, `359.5,100.0`. Note: Brightness is received separately in the `brightness_state_topic`.
+    #[serde(
+        rename = "hs_stat_t",
+        alias = "hs_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hs_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the HS value.
+    #[serde(
+        rename = "hs_val_tpl",
+        alias = "hs_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hs_value_template: Option<String>,
+
+    /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub json_attributes_template: Option<String>,
+
+    /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub json_attributes_topic: Option<String>,
+
+    /// The maximum color temperature in mireds.
+    #[serde(
+        rename = "max_mirs",
+        alias = "max_mireds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_mireds: Option<i32>,
+
+    /// The minimum color temperature in mireds.
+    #[serde(
+        rename = "min_mirs",
+        alias = "min_mireds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min_mireds: Option<i32>,
+
+    /// The name of the light. Can be set to `null` if only the device name is relevant.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Used instead of `name` for automatic generation of `entity_id`
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub object_id: Option<String>,
+
+    /// Defines when on the payload_on is sent. Using `last` (the default) will send any style (brightness, color, etc) topics first and then a `payload_on` to the `command_topic`. Using `first` will send the `payload_on` and then any style topics. Using `brightness` will only send brightness commands instead of the `payload_on` to turn the light on.
+    #[serde(
+        rename = "on_cmd_type",
+        alias = "on_command_type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub on_command_type: Option<String>,
+
+    /// Flag that defines if switch works in optimistic mode.
+    #[serde(
+        rename = "opt",
+        alias = "optimistic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub optimistic: Option<bool>,
+
+    /// The payload that represents disabled state.
+    #[serde(
+        rename = "pl_off",
+        alias = "payload_off",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payload_off: Option<String>,
+
+    /// The payload that represents enabled state.
+    #[serde(
+        rename = "pl_on",
+        alias = "payload_on",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payload_on: Option<String>,
+
+    /// Must be `light`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    #[serde(rename = "platform", alias = "p")]
+    pub platform: String,
+
+    /// The maximum QoS level to be used when receiving and publishing messages.
+    #[serde(rename = "qos", skip_serializing_if = "Option::is_none")]
+    pub qos: Option<Qos>,
+
+    /// If the published message should have the retain flag on or not.
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub retain: Option<bool>,
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `rgb_command_topic`. Available variables: `red`, `green` and `blue`.
+    #[serde(
+        rename = "rgb_cmd_tpl",
+        alias = "rgb_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgb_command_template: Option<String>,
+
+    /// The MQTT topic to publish commands to change the light's RGB state.
+    #[serde(
+        rename = "rgb_cmd_t",
+        alias = "rgb_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgb_command_topic: Option<String>,
+
+    /// The MQTT topic subscribed to receive RGB state updates. The expected payload is the RGB values separated by commas, for example, `255,0,127`.
+    #[serde(
+        rename = "rgb_stat_t",
+        alias = "rgb_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgb_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the RGB value.
+    #[serde(
+        rename = "rgb_val_tpl",
+        alias = "rgb_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgb_value_template: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `rgbw_command_topic`. Available variables: `red`, `green`, `blue` and `white`.
+    #[serde(
+        rename = "rgbw_cmd_tpl",
+        alias = "rgbw_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgbw_command_template: Option<String>,
+
+    /// The MQTT topic to publish commands to change the light's RGBW state.
+    #[serde(
+        rename = "rgbw_cmd_t",
+        alias = "rgbw_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgbw_command_topic: Option<String>,
+
+    /// The MQTT topic subscribed to receive RGBW state updates. The expected payload is the RGBW values separated by commas, for example, `255,0,127,64`.
+    #[serde(
+        rename = "rgbw_stat_t",
+        alias = "rgbw_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgbw_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the RGBW value.
+    #[serde(
+        rename = "rgbw_val_tpl",
+        alias = "rgbw_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgbw_value_template: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `rgbww_command_topic`. Available variables: `red`, `green`, `blue`, `cold_white` and `warm_white`.
+    #[serde(
+        rename = "rgbww_cmd_tpl",
+        alias = "rgbww_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgbww_command_template: Option<String>,
+
+    /// The MQTT topic to publish commands to change the light's RGBWW state.
+    #[serde(
+        rename = "rgbww_cmd_t",
+        alias = "rgbww_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgbww_command_topic: Option<String>,
+
+    /// The MQTT topic subscribed to receive RGBWW state updates. The expected payload is the RGBWW values separated by commas, for example, `255,0,127,64,32`.
+    #[serde(
+        rename = "rgbww_stat_t",
+        alias = "rgbww_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgbww_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the RGBWW value.
+    #[serde(
+        rename = "rgbww_val_tpl",
+        alias = "rgbww_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rgbww_value_template: Option<String>,
+
+    /// The schema to use. Must be `default` or omitted to select the default schema.
+    #[serde(rename = "schema")]
+    pub schema: LightSchema,
+
+    /// The MQTT topic subscribed to receive state updates. A "None" payload resets to an `unknown` state. An empty payload is ignored. By default, valid state payloads are `OFF` and `ON`. The accepted payloads can be overridden with the `payload_off` and `payload_on` config options.
+    #[serde(
+        rename = "stat_t",
+        alias = "state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the state value. The template should return the `payload_on` and `payload_off` values, so if your light uses `power on` to turn on, your `state_value_template` string should return `power on` when the switch is on. For example, if the message is just `on`, your `state_value_template` should be `power {{ value }}`. When your `payload_on = 27` and `payload_off = 'off'`, then this template might be `'off' if value_json.my_custom_brightness_field <= 0 else 27`.
+    #[serde(
+        rename = "stat_val_tpl",
+        alias = "state_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub state_value_template: Option<String>,
+
+    /// An ID that uniquely identifies this light. If two lights have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unique_id: Option<String>,
+
+    /// The MQTT topic to publish commands to change the light to white mode with a given brightness.
+    #[serde(
+        rename = "whit_cmd_t",
+        alias = "white_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub white_command_topic: Option<String>,
+
+    /// Defines the maximum white level (i.e., 100%) of the MQTT device.
+    #[serde(
+        rename = "whit_scl",
+        alias = "white_scale",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub white_scale: Option<i32>,
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `xy_command_topic`. Available variables: `x` and `y`.
+    #[serde(
+        rename = "xy_cmd_tpl",
+        alias = "xy_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub xy_command_template: Option<String>,
+
+    /// The MQTT topic to publish commands to change the light's XY state.
+    #[serde(
+        rename = "xy_cmd_t",
+        alias = "xy_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub xy_command_topic: Option<String>,
+
+    /// The MQTT topic subscribed to receive XY state updates. The expected payload is the X and Y color values separated by commas, for example, `0.675,0.322`.
+    #[serde(
+        rename = "xy_stat_t",
+        alias = "xy_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub xy_state_topic: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the XY value.
+    #[serde(
+        rename = "xy_val_tpl",
+        alias = "xy_value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub xy_value_template: Option<String>,
+}
+
+impl BasicLight {
+    /// Replaces `~` with this value in any MQTT topic attribute.
+    /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+    pub fn topic_prefix<S: Into<String>>(mut self, topic_prefix: S) -> Self {
+        self.topic_prefix = Some(topic_prefix.into());
+        self
+    }
+
+    /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Information about the device this sensor is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/device_registry_index/). Only works when `unique_id` is set. At least one of identifiers or connections must be present to identify the device.
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = device;
+        self
+    }
+
+    /// The category of the entity. (optional, default: None)
+    pub fn entity_category(mut self, entity_category: EntityCategory) -> Self {
+        self.entity_category = Some(entity_category);
+        self
+    }
+
+    /// Defines how HA will check for entity availability.
+    pub fn availability(mut self, availability: Availability) -> Self {
+        self.availability = availability;
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light’s brightness.
+    pub fn brightness_command_topic<T: Into<String>>(
+        mut self,
+        brightness_command_topic: T,
+    ) -> Self {
+        self.brightness_command_topic = Some(brightness_command_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `brightness_command_topic`. Available variables: `value`.
+    pub fn brightness_command_template<T: Into<String>>(
+        mut self,
+        brightness_command_template: T,
+    ) -> Self {
+        self.brightness_command_template = Some(brightness_command_template.into());
+        self
+    }
+
+    /// Defines the maximum brightness value (i.e., 100%) of the MQTT device.
+    pub fn brightness_scale(mut self, brightness_scale: i32) -> Self {
+        self.brightness_scale = Some(brightness_scale);
+        self
+    }
+
+    /// The MQTT topic subscribed to receive brightness state updates.
+    pub fn brightness_state_topic<T: Into<String>>(mut self, brightness_state_topic: T) -> Self {
+        self.brightness_state_topic = Some(brightness_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the brightness value.
+    pub fn brightness_value_template<T: Into<String>>(
+        mut self,
+        brightness_value_template: T,
+    ) -> Self {
+        self.brightness_value_template = Some(brightness_value_template.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive color mode updates. If this is not configured, `color_mode` will be automatically set according to the last received valid color or color temperature
+    pub fn color_mode_state_topic<T: Into<String>>(mut self, color_mode_state_topic: T) -> Self {
+        self.color_mode_state_topic = Some(color_mode_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the color mode.
+    pub fn color_mode_value_template<T: Into<String>>(
+        mut self,
+        color_mode_value_template: T,
+    ) -> Self {
+        self.color_mode_value_template = Some(color_mode_value_template.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `color_temp_command_topic`. Available variables: `value`.
+    pub fn color_temp_command_template<T: Into<String>>(
+        mut self,
+        color_temp_command_template: T,
+    ) -> Self {
+        self.color_temp_command_template = Some(color_temp_command_template.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light’s color temperature state. The color temperature command slider has a range of 153 to 500 mireds (micro reciprocal degrees).
+    pub fn color_temp_command_topic<T: Into<String>>(
+        mut self,
+        color_temp_command_topic: T,
+    ) -> Self {
+        self.color_temp_command_topic = Some(color_temp_command_topic.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive color temperature state updates.
+    pub fn color_temp_state_topic<T: Into<String>>(mut self, color_temp_state_topic: T) -> Self {
+        self.color_temp_state_topic = Some(color_temp_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the color temperature value.
+    pub fn color_temp_value_template<T: Into<String>>(
+        mut self,
+        color_temp_value_template: T,
+    ) -> Self {
+        self.color_temp_value_template = Some(color_temp_value_template.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the switch state.
+    pub fn command_topic<T: Into<String>>(mut self, command_topic: T) -> Self {
+        self.command_topic = command_topic.into();
+        self
+    }
+
+    /// Flag which defines if the entity should be enabled when first added.
+    pub fn enabled_by_default(mut self, enabled_by_default: bool) -> Self {
+        self.enabled_by_default = Some(enabled_by_default);
+        self
+    }
+
+    /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
+    pub fn encoding<T: Into<String>>(mut self, encoding: T) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// Picture URL for the entity.
+    pub fn entity_picture<T: Into<String>>(mut self, entity_picture: T) -> Self {
+        self.entity_picture = Some(entity_picture.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light's effect state.
+    pub fn effect_command_topic<T: Into<String>>(mut self, effect_command_topic: T) -> Self {
+        self.effect_command_topic = Some(effect_command_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `effect_command_topic`. Available variables: `value`.
+    pub fn effect_command_template<T: Into<String>>(mut self, effect_command_template: T) -> Self {
+        self.effect_command_template = Some(effect_command_template.into());
+        self
+    }
+
+    /// The list of effects the light supports.
+    pub fn effect_list<T: Into<String>>(mut self, effect_list: Vec<T>) -> Self {
+        self.effect_list = Some(effect_list.into_iter().map(|v| v.into()).collect());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive effect state updates.
+    pub fn effect_state_topic<T: Into<String>>(mut self, effect_state_topic: T) -> Self {
+        self.effect_state_topic = Some(effect_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the effect value.
+    pub fn effect_value_template<T: Into<String>>(mut self, effect_value_template: T) -> Self {
+        self.effect_value_template = Some(effect_value_template.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `hs_command_topic`. Available variables: `hue` and `sat`.
+    pub fn hs_command_template<T: Into<String>>(mut self, hs_command_template: T) -> Self {
+        self.hs_command_template = Some(hs_command_template.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light's color state in HS format (Hue Saturation). Range for Hue: 0° .. 360°, Range of Saturation: 0..100. Note: Brightness is sent separately in the `brightness_command_topic`.
+    pub fn hs_command_topic<T: Into<String>>(mut self, hs_command_topic: T) -> Self {
+        self.hs_command_topic = Some(hs_command_topic.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive color state updates in HS format. The expected payload is the hue and saturation values separated by commas, for example, `359.5,100.0`. Note: Brightness is received separately in the `brightness_state_topic`.
+    pub fn hs_state_topic<T: Into<String>>(mut self, hs_state_topic: T) -> Self {
+        self.hs_state_topic = Some(hs_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the HS value.
+    pub fn hs_value_template<T: Into<String>>(mut self, hs_value_template: T) -> Self {
+        self.hs_value_template = Some(hs_value_template.into());
+        self
+    }
+
+    /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
+    pub fn icon<T: Into<String>>(mut self, icon: T) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
+    pub fn json_attributes_template<T: Into<String>>(
+        mut self,
+        json_attributes_template: T,
+    ) -> Self {
+        self.json_attributes_template = Some(json_attributes_template.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
+    pub fn json_attributes_topic<T: Into<String>>(mut self, json_attributes_topic: T) -> Self {
+        self.json_attributes_topic = Some(json_attributes_topic.into());
+        self
+    }
+
+    /// The maximum color temperature in mireds.
+    pub fn max_mireds(mut self, max_mireds: i32) -> Self {
+        self.max_mireds = Some(max_mireds);
+        self
+    }
+
+    /// The minimum color temperature in mireds.
+    pub fn min_mireds(mut self, min_mireds: i32) -> Self {
+        self.min_mireds = Some(min_mireds);
+        self
+    }
+
+    /// The name of the light. Can be set to `null` if only the device name is relevant.
+    pub fn name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Used instead of `name` for automatic generation of `entity_id`
+    pub fn object_id<T: Into<String>>(mut self, object_id: T) -> Self {
+        self.object_id = Some(object_id.into());
+        self
+    }
+
+    /// Defines when on the payload_on is sent. Using `last` (the default) will send any style (brightness, color, etc) topics first and then a `payload_on` to the `command_topic`. Using `first` will send the `payload_on` and then any style topics. Using `brightness` will only send brightness commands instead of the `payload_on` to turn the light on.
+    pub fn on_command_type<T: Into<String>>(mut self, on_command_type: T) -> Self {
+        self.on_command_type = Some(on_command_type.into());
+        self
+    }
+
+    /// Flag that defines if switch works in optimistic mode.
+    pub fn optimistic(mut self, optimistic: bool) -> Self {
+        self.optimistic = Some(optimistic);
+        self
+    }
+
+    /// The payload that represents disabled state.
+    pub fn payload_off<T: Into<String>>(mut self, payload_off: T) -> Self {
+        self.payload_off = Some(payload_off.into());
+        self
+    }
+
+    /// The payload that represents enabled state.
+    pub fn payload_on<T: Into<String>>(mut self, payload_on: T) -> Self {
+        self.payload_on = Some(payload_on.into());
+        self
+    }
+
+    /// Must be `light`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    pub fn platform<T: Into<String>>(mut self, platform: T) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
+    /// The maximum QoS level to be used when receiving and publishing messages.
+    pub fn qos(mut self, qos: Qos) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// If the published message should have the retain flag on or not.
+    pub fn retain(mut self, retain: bool) -> Self {
+        self.retain = Some(retain);
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `rgb_command_topic`. Available variables: `red`, `green` and `blue`.
+    pub fn rgb_command_template<T: Into<String>>(mut self, rgb_command_template: T) -> Self {
+        self.rgb_command_template = Some(rgb_command_template.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light's RGB state.
+    pub fn rgb_command_topic<T: Into<String>>(mut self, rgb_command_topic: T) -> Self {
+        self.rgb_command_topic = Some(rgb_command_topic.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive RGB state updates. The expected payload is the RGB values separated by commas, for example, `255,0,127`.
+    pub fn rgb_state_topic<T: Into<String>>(mut self, rgb_state_topic: T) -> Self {
+        self.rgb_state_topic = Some(rgb_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the RGB value.
+    pub fn rgb_value_template<T: Into<String>>(mut self, rgb_value_template: T) -> Self {
+        self.rgb_value_template = Some(rgb_value_template.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `rgbw_command_topic`. Available variables: `red`, `green`, `blue` and `white`.
+    pub fn rgbw_command_template<T: Into<String>>(mut self, rgbw_command_template: T) -> Self {
+        self.rgbw_command_template = Some(rgbw_command_template.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light's RGBW state.
+    pub fn rgbw_command_topic<T: Into<String>>(mut self, rgbw_command_topic: T) -> Self {
+        self.rgbw_command_topic = Some(rgbw_command_topic.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive RGBW state updates. The expected payload is the RGBW values separated by commas, for example, `255,0,127,64`.
+    pub fn rgbw_state_topic<T: Into<String>>(mut self, rgbw_state_topic: T) -> Self {
+        self.rgbw_state_topic = Some(rgbw_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the RGBW value.
+    pub fn rgbw_value_template<T: Into<String>>(mut self, rgbw_value_template: T) -> Self {
+        self.rgbw_value_template = Some(rgbw_value_template.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `rgbww_command_topic`. Available variables: `red`, `green`, `blue`, `cold_white` and `warm_white`.
+    pub fn rgbww_command_template<T: Into<String>>(mut self, rgbww_command_template: T) -> Self {
+        self.rgbww_command_template = Some(rgbww_command_template.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light's RGBWW state.
+    pub fn rgbww_command_topic<T: Into<String>>(mut self, rgbww_command_topic: T) -> Self {
+        self.rgbww_command_topic = Some(rgbww_command_topic.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive RGBWW state updates. The expected payload is the RGBWW values separated by commas, for example, `255,0,127,64,32`.
+    pub fn rgbww_state_topic<T: Into<String>>(mut self, rgbww_state_topic: T) -> Self {
+        self.rgbww_state_topic = Some(rgbww_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the RGBWW value.
+    pub fn rgbww_value_template<T: Into<String>>(mut self, rgbww_value_template: T) -> Self {
+        self.rgbww_value_template = Some(rgbww_value_template.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive state updates. A "None" payload resets to an `unknown` state. An empty payload is ignored. By default, valid state payloads are `OFF` and `ON`. The accepted payloads can be overridden with the `payload_off` and `payload_on` config options.
+    pub fn state_topic<T: Into<String>>(mut self, state_topic: T) -> Self {
+        self.state_topic = Some(state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the state value. The template should return the `payload_on` and `payload_off` values, so if your light uses `power on` to turn on, your `state_value_template` string should return `power on` when the switch is on. For example, if the message is just `on`, your `state_value_template` should be `power {{ value }}`. When your `payload_on = 27` and `payload_off = 'off'`, then this template might be `'off' if value_json.my_custom_brightness_field <= 0 else 27`.
+    pub fn state_value_template<T: Into<String>>(mut self, state_value_template: T) -> Self {
+        self.state_value_template = Some(state_value_template.into());
+        self
+    }
+
+    /// An ID that uniquely identifies this light. If two lights have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
+    pub fn unique_id<T: Into<String>>(mut self, unique_id: T) -> Self {
+        self.unique_id = Some(unique_id.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light to white mode with a given brightness.
+    pub fn white_command_topic<T: Into<String>>(mut self, white_command_topic: T) -> Self {
+        self.white_command_topic = Some(white_command_topic.into());
+        self
+    }
+
+    /// Defines the maximum white level (i.e., 100%) of the MQTT device.
+    pub fn white_scale(mut self, white_scale: i32) -> Self {
+        self.white_scale = Some(white_scale);
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/) to compose message which will be sent to `xy_command_topic`. Available variables: `x` and `y`.
+    pub fn xy_command_template<T: Into<String>>(mut self, xy_command_template: T) -> Self {
+        self.xy_command_template = Some(xy_command_template.into());
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light's XY state.
+    pub fn xy_command_topic<T: Into<String>>(mut self, xy_command_topic: T) -> Self {
+        self.xy_command_topic = Some(xy_command_topic.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive XY state updates. The expected payload is the X and Y color values separated by commas, for example, `0.675,0.322`.
+    pub fn xy_state_topic<T: Into<String>>(mut self, xy_state_topic: T) -> Self {
+        self.xy_state_topic = Some(xy_state_topic.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the XY value.
+    pub fn xy_value_template<T: Into<String>>(mut self, xy_value_template: T) -> Self {
+        self.xy_value_template = Some(xy_value_template.into());
+        self
+    }
+}
+
+impl Default for BasicLight {
+    fn default() -> Self {
+        Self {
+            topic_prefix: Default::default(),
+            origin: Default::default(),
+            device: Default::default(),
+            entity_category: Default::default(),
+            availability: Default::default(),
+            brightness_command_topic: Default::default(),
+            brightness_command_template: Default::default(),
+            brightness_scale: Default::default(),
+            brightness_state_topic: Default::default(),
+            brightness_value_template: Default::default(),
+            color_mode_state_topic: Default::default(),
+            color_mode_value_template: Default::default(),
+            color_temp_command_template: Default::default(),
+            color_temp_command_topic: Default::default(),
+            color_temp_state_topic: Default::default(),
+            color_temp_value_template: Default::default(),
+            command_topic: Default::default(),
+            enabled_by_default: Default::default(),
+            encoding: Default::default(),
+            entity_picture: Default::default(),
+            effect_command_topic: Default::default(),
+            effect_command_template: Default::default(),
+            effect_list: Default::default(),
+            effect_state_topic: Default::default(),
+            effect_value_template: Default::default(),
+            hs_command_template: Default::default(),
+            hs_command_topic: Default::default(),
+            hs_state_topic: Default::default(),
+            hs_value_template: Default::default(),
+            icon: Default::default(),
+            json_attributes_template: Default::default(),
+            json_attributes_topic: Default::default(),
+            max_mireds: Default::default(),
+            min_mireds: Default::default(),
+            name: Default::default(),
+            object_id: Default::default(),
+            on_command_type: Default::default(),
+            optimistic: Default::default(),
+            payload_off: Default::default(),
+            payload_on: Default::default(),
+            platform: "light".to_string(),
+            qos: Default::default(),
+            retain: Default::default(),
+            rgb_command_template: Default::default(),
+            rgb_command_topic: Default::default(),
+            rgb_state_topic: Default::default(),
+            rgb_value_template: Default::default(),
+            rgbw_command_template: Default::default(),
+            rgbw_command_topic: Default::default(),
+            rgbw_state_topic: Default::default(),
+            rgbw_value_template: Default::default(),
+            rgbww_command_template: Default::default(),
+            rgbww_command_topic: Default::default(),
+            rgbww_state_topic: Default::default(),
+            rgbww_value_template: Default::default(),
+            schema: LightSchema::Basic,
+            state_topic: Default::default(),
+            state_value_template: Default::default(),
+            unique_id: Default::default(),
+            white_command_topic: Default::default(),
+            white_scale: Default::default(),
+            xy_command_template: Default::default(),
+            xy_command_topic: Default::default(),
+            xy_state_topic: Default::default(),
+            xy_value_template: Default::default(),
+        }
+    }
+}
+
+impl From<BasicLight> for Light {
+    fn from(value: BasicLight) -> Self {
+        Light::Basic(value)
+    }
+}
+
+impl From<BasicLight> for Entity {
+    fn from(value: BasicLight) -> Self {
+        Entity::Light(Light::Basic(value))
+    }
+}
+
+/// MQTT discovery configuration of a `light` entity using the JSON schema.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/light.mqtt/#json-schema)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonLight {
+    /// Replaces `~` with this value in any MQTT topic attribute.
+    /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+    #[serde(rename = "~", skip_serializing_if = "Option::is_none")]
+    pub topic_prefix: Option<String>,
+
+    /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
+    #[serde(rename = "o", alias = "origin")]
+    pub origin: Origin,
+
+    /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
+    #[serde(rename = "dev", alias = "device")]
+    pub device: Device,
+
+    /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
+    #[serde(flatten)]
+    pub availability: Availability,
+
+    /// The category of the entity. (optional, default: None)
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_category: Option<EntityCategory>,
+
+    /// Flag that defines if light supports brightness when the `rgb`, `rgbw`, or `rgbww` color mode is supported.
+    #[serde(rename = "brightness", skip_serializing_if = "Option::is_none")]
+    pub brightness: Option<bool>,
+
+    /// Defines the maximum brightness value (i.e., 100%) of the MQTT device.
+    #[serde(
+        rename = "bri_scl",
+        alias = "brightness_scale",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub brightness_scale: Option<i32>,
+
+    /// The MQTT topic to publish commands to change the light’s state.
+    #[serde(rename = "cmd_t", alias = "command_topic")]
+    pub command_topic: String,
+
+    /// Flag which defines if the entity should be enabled when first added.
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enabled_by_default: Option<bool>,
+
+    /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encoding: Option<String>,
+
+    /// Flag that defines if the light supports effects.
+    #[serde(rename = "effect", skip_serializing_if = "Option::is_none")]
+    pub effect: Option<bool>,
+
+    /// The list of effects the light supports.
+    #[serde(
+        rename = "fx_list",
+        alias = "effect_list",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effect_list: Option<Vec<String>>,
+
+    /// The duration, in seconds, of a “long” flash.
+    #[serde(
+        rename = "flsh_tlng",
+        alias = "flash_time_long",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub flash_time_long: Option<i32>,
+
+    /// The duration, in seconds, of a “short” flash.
+    #[serde(
+        rename = "flsh_tsht",
+        alias = "flash_time_short",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub flash_time_short: Option<i32>,
+
+    /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub json_attributes_template: Option<String>,
+
+    /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub json_attributes_topic: Option<String>,
+
+    /// The maximum color temperature in mireds.
+    #[serde(
+        rename = "max_mirs",
+        alias = "max_mireds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_mireds: Option<i32>,
+
+    /// The minimum color temperature in mireds.
+    #[serde(
+        rename = "min_mirs",
+        alias = "min_mireds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min_mireds: Option<i32>,
+
+    /// The name of the light.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Used instead of `name` for automatic generation of `entity_id`
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub object_id: Option<String>,
+
+    /// Flag that defines if the light works in optimistic mode.
+    #[serde(
+        rename = "opt",
+        alias = "optimistic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub optimistic: Option<bool>,
+
+    /// Must be `light`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    #[serde(rename = "platform", alias = "p")]
+    pub platform: String,
+
+    /// The maximum QoS level to be used when receiving and publishing messages.
+    #[serde(rename = "qos", skip_serializing_if = "Option::is_none")]
+    pub qos: Option<Qos>,
+
+    /// If the published message should have the retain flag on or not.
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub retain: Option<bool>,
+
+    /// The schema to use. Must be `json` to select the JSON schema.
+    #[serde(rename = "schema")]
+    pub schema: LightSchema,
+
+    /// The MQTT topic subscribed to receive state updates in a JSON-format. The JSON payload may contain the elements: `"state"`: `"ON"` the light is on, `"OFF"` the light is off, `null` the state is `unknown`; `"color_mode"`: one of the `supported_color_modes`; `"color"`: A dict with the color attributes*; `"brightness"`: The brightness; `"color_temp"`: The color temperature; `"effect"`: The effect of the light.
+    #[serde(
+        rename = "stat_t",
+        alias = "state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub state_topic: Option<String>,
+
+    /// A list of color modes supported by the list. Possible color modes are `onoff`, `brightness`, `color_temp`, `hs`, `xy`, `rgb`, `rgbw`, `rgbww`, `white`. Note that if `onoff` **or** `brightness` are used, that must be the _only_ value in the list.
+    #[serde(
+        rename = "sup_clrm",
+        alias = "supported_color_modes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub supported_color_modes: Option<Vec<ColorMode>>,
+
+    /// An ID that uniquely identifies this light. If two lights have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unique_id: Option<String>,
+
+    /// Defines the maximum white level (i.e., 100%) of the MQTT device. This is used when setting the light to white mode.
+    #[serde(
+        rename = "whit_scl",
+        alias = "white_scale",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub white_scale: Option<i32>,
+}
+
+impl JsonLight {
+    /// Replaces `~` with this value in any MQTT topic attribute.
+    /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+    pub fn topic_prefix<S: Into<String>>(mut self, topic_prefix: S) -> Self {
+        self.topic_prefix = Some(topic_prefix.into());
+        self
+    }
+
+    /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Information about the device this sensor is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/device_registry_index/). Only works when `unique_id` is set. At least one of identifiers or connections must be present to identify the device.
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = device;
+        self
+    }
+
+    /// The category of the entity. (optional, default: None)
+    pub fn entity_category(mut self, entity_category: EntityCategory) -> Self {
+        self.entity_category = Some(entity_category);
+        self
+    }
+
+    /// Defines how HA will check for entity availability.
+    pub fn availability(mut self, availability: Availability) -> Self {
+        self.availability = availability;
+        self
+    }
+
+    /// Flag that defines if light supports brightness when the `rgb`, `rgbw`, or `rgbww` color mode is supported.
+    pub fn brightness(mut self, brightness: bool) -> Self {
+        self.brightness = Some(brightness);
+        self
+    }
+
+    /// Defines the maximum brightness value (i.e., 100%) of the MQTT device.
+    pub fn brightness_scale(mut self, brightness_scale: i32) -> Self {
+        self.brightness_scale = Some(brightness_scale);
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light’s state.
+    pub fn command_topic<T: Into<String>>(mut self, command_topic: T) -> Self {
+        self.command_topic = command_topic.into();
+        self
+    }
+
+    /// Flag which defines if the entity should be enabled when first added.
+    pub fn enabled_by_default(mut self, enabled_by_default: bool) -> Self {
+        self.enabled_by_default = Some(enabled_by_default);
+        self
+    }
+
+    /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
+    pub fn encoding<T: Into<String>>(mut self, encoding: T) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// Flag that defines if the light supports effects.
+    pub fn effect(mut self, effect: bool) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+
+    /// The list of effects the light supports.
+    pub fn effect_list<T: Into<String>>(mut self, effect_list: Vec<T>) -> Self {
+        self.effect_list = Some(effect_list.into_iter().map(|v| v.into()).collect());
+        self
+    }
+
+    /// The duration, in seconds, of a “long” flash.
+    pub fn flash_time_long(mut self, flash_time_long: i32) -> Self {
+        self.flash_time_long = Some(flash_time_long);
+        self
+    }
+
+    /// The duration, in seconds, of a “short” flash.
+    pub fn flash_time_short(mut self, flash_time_short: i32) -> Self {
+        self.flash_time_short = Some(flash_time_short);
+        self
+    }
+
+    /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
+    pub fn icon<T: Into<String>>(mut self, icon: T) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
+    pub fn json_attributes_template<T: Into<String>>(
+        mut self,
+        json_attributes_template: T,
+    ) -> Self {
+        self.json_attributes_template = Some(json_attributes_template.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
+    pub fn json_attributes_topic<T: Into<String>>(mut self, json_attributes_topic: T) -> Self {
+        self.json_attributes_topic = Some(json_attributes_topic.into());
+        self
+    }
+
+    /// The maximum color temperature in mireds.
+    pub fn max_mireds(mut self, max_mireds: i32) -> Self {
+        self.max_mireds = Some(max_mireds);
+        self
+    }
+
+    /// The minimum color temperature in mireds.
+    pub fn min_mireds(mut self, min_mireds: i32) -> Self {
+        self.min_mireds = Some(min_mireds);
+        self
+    }
+
+    /// The name of the light.
+    pub fn name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Used instead of `name` for automatic generation of `entity_id`
+    pub fn object_id<T: Into<String>>(mut self, object_id: T) -> Self {
+        self.object_id = Some(object_id.into());
+        self
+    }
+
+    /// Flag that defines if the light works in optimistic mode.
+    pub fn optimistic(mut self, optimistic: bool) -> Self {
+        self.optimistic = Some(optimistic);
+        self
+    }
+
+    /// Must be `light`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    pub fn platform<T: Into<String>>(mut self, platform: T) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
+    /// The maximum QoS level to be used when receiving and publishing messages.
+    pub fn qos(mut self, qos: Qos) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// If the published message should have the retain flag on or not.
+    pub fn retain(mut self, retain: bool) -> Self {
+        self.retain = Some(retain);
+        self
+    }
+
+    /// The MQTT topic subscribed to receive state updates in a JSON-format. The JSON payload may contain the elements: `"state"`: `"ON"` the light is on, `"OFF"` the light is off, `null` the state is `unknown`; `"color_mode"`: one of the `supported_color_modes`; `"color"`: A dict with the color attributes*; `"brightness"`: The brightness; `"color_temp"`: The color temperature; `"effect"`: The effect of the light.
+    pub fn state_topic<T: Into<String>>(mut self, state_topic: T) -> Self {
+        self.state_topic = Some(state_topic.into());
+        self
+    }
+
+    /// A list of color modes supported by the list. Possible color modes are `onoff`, `brightness`, `color_temp`, `hs`, `xy`, `rgb`, `rgbw`, `rgbww`, `white`. Note that if `onoff` **or** `brightness` are used, that must be the _only_ value in the list.
+    pub fn supported_color_modes(mut self, supported_color_modes: Vec<ColorMode>) -> Self {
+        self.supported_color_modes = Some(supported_color_modes);
+        self
+    }
+
+    /// An ID that uniquely identifies this light. If two lights have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
+    pub fn unique_id<T: Into<String>>(mut self, unique_id: T) -> Self {
+        self.unique_id = Some(unique_id.into());
+        self
+    }
+
+    /// Defines the maximum white level (i.e., 100%) of the MQTT device. This is used when setting the light to white mode.
+    pub fn white_scale(mut self, white_scale: i32) -> Self {
+        self.white_scale = Some(white_scale);
+        self
+    }
+}
+
+impl Default for JsonLight {
+    fn default() -> Self {
+        Self {
+            topic_prefix: Default::default(),
+            origin: Default::default(),
+            device: Default::default(),
+            entity_category: Default::default(),
+            availability: Default::default(),
+            brightness: Default::default(),
+            brightness_scale: Default::default(),
+            command_topic: Default::default(),
+            enabled_by_default: Default::default(),
+            encoding: Default::default(),
+            effect: Default::default(),
+            effect_list: Default::default(),
+            flash_time_long: Default::default(),
+            flash_time_short: Default::default(),
+            icon: Default::default(),
+            json_attributes_template: Default::default(),
+            json_attributes_topic: Default::default(),
+            max_mireds: Default::default(),
+            min_mireds: Default::default(),
+            name: Default::default(),
+            object_id: Default::default(),
+            optimistic: Default::default(),
+            platform: "light".to_string(),
+            qos: Default::default(),
+            retain: Default::default(),
+            schema: LightSchema::Json,
+            state_topic: Default::default(),
+            supported_color_modes: Default::default(),
+            unique_id: Default::default(),
+            white_scale: Default::default(),
+        }
+    }
+}
+
+impl From<JsonLight> for Light {
+    fn from(value: JsonLight) -> Self {
+        Light::Json(value)
+    }
+}
+
+impl From<JsonLight> for Entity {
+    fn from(value: JsonLight) -> Self {
+        Entity::Light(Light::Json(value))
+    }
+}
+
+/// MQTT discovery configuration of a `light` entity using the template schema.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/light.mqtt/#template-schema)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TemplateLight {
+    /// Replaces `~` with this value in any MQTT topic attribute.
+    /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+    #[serde(rename = "~", skip_serializing_if = "Option::is_none")]
+    pub topic_prefix: Option<String>,
+
+    /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
+    #[serde(rename = "o", alias = "origin")]
+    pub origin: Origin,
+
+    /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
+    #[serde(rename = "dev", alias = "device")]
+    pub device: Device,
+
+    /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
+    #[serde(flatten)]
+    pub availability: Availability,
+
+    /// The category of the entity. (optional, default: None)
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_category: Option<EntityCategory>,
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract blue color from the state payload value. Expected result of the template is an integer from 0-255 range.
+    #[serde(
+        rename = "b_tpl",
+        alias = "blue_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub blue_template: Option<String>,
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract brightness from the state payload value. Expected result of the template is an integer from 0-255 range.
+    #[serde(
+        rename = "bri_tpl",
+        alias = "brightness_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub brightness_template: Option<String>,
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract color temperature from the state payload value. Expected result of the template is an integer representing mired units.
+    #[serde(
+        rename = "clr_temp_tpl",
+        alias = "color_temp_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_temp_template: Option<String>,
+
+    /// The [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) for *off* state changes. Available variables: `state` and `transition`.
+    #[serde(rename = "cmd_off_tpl", alias = "command_off_template")]
+    pub command_off_template: String,
+
+    /// The [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) for *on* state changes. Available variables: `state`, `brightness`, `color_temp`, `red`, `green`, `blue`, `hue`, `sat`, `flash`, `transition` and `effect`. Values `red`, `green`, `blue`, `brightness` are provided as integers from range 0-255. Value of `hue` is provided as float from range 0-360. Value of `sat` is provided as float from range 0-100. Value of `color_temp` is provided as integer representing mired units.
+    #[serde(rename = "cmd_on_tpl", alias = "command_on_template")]
+    pub command_on_template: String,
+
+    /// The MQTT topic to publish commands to change the light’s state.
+    #[serde(rename = "cmd_t", alias = "command_topic")]
+    pub command_topic: String,
+
+    /// Flag which defines if the entity should be enabled when first added.
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enabled_by_default: Option<bool>,
+
+    /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encoding: Option<String>,
+
+    /// List of possible effects.
+    #[serde(
+        rename = "fx_list",
+        alias = "effect_list",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effect_list: Option<Vec<String>>,
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract effect from the state payload value.
+    #[serde(
+        rename = "fx_tpl",
+        alias = "effect_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effect_template: Option<String>,
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract green color from the state payload value. Expected result of the template is an integer from 0-255 range.
+    #[serde(
+        rename = "g_tpl",
+        alias = "green_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub green_template: Option<String>,
+
+    /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub json_attributes_template: Option<String>,
+
+    /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub json_attributes_topic: Option<String>,
+
+    /// The maximum color temperature in mireds.
+    #[serde(
+        rename = "max_mirs",
+        alias = "max_mireds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_mireds: Option<i32>,
+
+    /// The minimum color temperature in mireds.
+    #[serde(
+        rename = "min_mirs",
+        alias = "min_mireds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min_mireds: Option<i32>,
+
+    /// The name of the light.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Used instead of `name` for automatic generation of `entity_id`
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub object_id: Option<String>,
+
+    /// Flag that defines if the light works in optimistic mode.
+    #[serde(
+        rename = "opt",
+        alias = "optimistic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub optimistic: Option<bool>,
+
+    /// Must be `light`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    #[serde(rename = "platform", alias = "p")]
+    pub platform: String,
+
+    /// The maximum QoS level to be used when receiving and publishing messages.
+    #[serde(rename = "qos", skip_serializing_if = "Option::is_none")]
+    pub qos: Option<Qos>,
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract red color from the state payload value. Expected result of the template is an integer from 0-255 range.
+    #[serde(
+        rename = "r_tpl",
+        alias = "red_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub red_template: Option<String>,
+
+    /// The schema to use. Must be `template` to select the template schema.
+    #[serde(rename = "schema")]
+    pub schema: LightSchema,
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract state from the state payload value.
+    #[serde(
+        rename = "stat_tpl",
+        alias = "state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub state_template: Option<String>,
+
+    /// The MQTT topic subscribed to receive state updates. A "None" payload resets to an `unknown` state. An empty payload is ignored.
+    #[serde(
+        rename = "stat_t",
+        alias = "state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub state_topic: Option<String>,
+
+    /// An ID that uniquely identifies this light. If two lights have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unique_id: Option<String>,
+}
+
+impl TemplateLight {
+    /// Replaces `~` with this value in any MQTT topic attribute.
+    /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+    pub fn topic_prefix<S: Into<String>>(mut self, topic_prefix: S) -> Self {
+        self.topic_prefix = Some(topic_prefix.into());
+        self
+    }
+
+    /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Information about the device this sensor is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/device_registry_index/). Only works when `unique_id` is set. At least one of identifiers or connections must be present to identify the device.
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = device;
+        self
+    }
+
+    /// The category of the entity. (optional, default: None)
+    pub fn entity_category(mut self, entity_category: EntityCategory) -> Self {
+        self.entity_category = Some(entity_category);
+        self
+    }
+
+    /// Defines how HA will check for entity availability.
+    pub fn availability(mut self, availability: Availability) -> Self {
+        self.availability = availability;
+        self
+    }
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract blue color from the state payload value. Expected result of the template is an integer from 0-255 range.
+    pub fn blue_template<T: Into<String>>(mut self, blue_template: T) -> Self {
+        self.blue_template = Some(blue_template.into());
+        self
+    }
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract brightness from the state payload value. Expected result of the template is an integer from 0-255 range.
+    pub fn brightness_template<T: Into<String>>(mut self, brightness_template: T) -> Self {
+        self.brightness_template = Some(brightness_template.into());
+        self
+    }
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract color temperature from the state payload value. Expected result of the template is an integer representing mired units.
+    pub fn color_temp_template<T: Into<String>>(mut self, color_temp_template: T) -> Self {
+        self.color_temp_template = Some(color_temp_template.into());
+        self
+    }
+
+    /// The [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) for *off* state changes. Available variables: `state` and `transition`.
+    pub fn command_off_template<T: Into<String>>(mut self, command_off_template: T) -> Self {
+        self.command_off_template = command_off_template.into();
+        self
+    }
+
+    /// The [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) for *on* state changes. Available variables: `state`, `brightness`, `color_temp`, `red`, `green`, `blue`, `hue`, `sat`, `flash`, `transition` and `effect`. Values `red`, `green`, `blue`, `brightness` are provided as integers from range 0-255. Value of `hue` is provided as float from range 0-360. Value of `sat` is provided as float from range 0-100. Value of `color_temp` is provided as integer representing mired units.
+    pub fn command_on_template<T: Into<String>>(mut self, command_on_template: T) -> Self {
+        self.command_on_template = command_on_template.into();
+        self
+    }
+
+    /// The MQTT topic to publish commands to change the light’s state.
+    pub fn command_topic<T: Into<String>>(mut self, command_topic: T) -> Self {
+        self.command_topic = command_topic.into();
+        self
+    }
+
+    /// Flag which defines if the entity should be enabled when first added.
+    pub fn enabled_by_default(mut self, enabled_by_default: bool) -> Self {
+        self.enabled_by_default = Some(enabled_by_default);
+        self
+    }
+
+    /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
+    pub fn encoding<T: Into<String>>(mut self, encoding: T) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// List of possible effects.
+    pub fn effect_list<T: Into<String>>(mut self, effect_list: Vec<T>) -> Self {
+        self.effect_list = Some(effect_list.into_iter().map(|v| v.into()).collect());
+        self
+    }
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract effect from the state payload value.
+    pub fn effect_template<T: Into<String>>(mut self, effect_template: T) -> Self {
+        self.effect_template = Some(effect_template.into());
+        self
+    }
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract green color from the state payload value. Expected result of the template is an integer from 0-255 range.
+    pub fn green_template<T: Into<String>>(mut self, green_template: T) -> Self {
+        self.green_template = Some(green_template.into());
+        self
+    }
+
+    /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
+    pub fn icon<T: Into<String>>(mut self, icon: T) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
+    pub fn json_attributes_template<T: Into<String>>(
+        mut self,
+        json_attributes_template: T,
+    ) -> Self {
+        self.json_attributes_template = Some(json_attributes_template.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
+    pub fn json_attributes_topic<T: Into<String>>(mut self, json_attributes_topic: T) -> Self {
+        self.json_attributes_topic = Some(json_attributes_topic.into());
+        self
+    }
+
+    /// The maximum color temperature in mireds.
+    pub fn max_mireds(mut self, max_mireds: i32) -> Self {
+        self.max_mireds = Some(max_mireds);
+        self
+    }
+
+    /// The minimum color temperature in mireds.
+    pub fn min_mireds(mut self, min_mireds: i32) -> Self {
+        self.min_mireds = Some(min_mireds);
+        self
+    }
+
+    /// The name of the light.
+    pub fn name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Used instead of `name` for automatic generation of `entity_id`
+    pub fn object_id<T: Into<String>>(mut self, object_id: T) -> Self {
+        self.object_id = Some(object_id.into());
+        self
+    }
+
+    /// Flag that defines if the light works in optimistic mode.
+    pub fn optimistic(mut self, optimistic: bool) -> Self {
+        self.optimistic = Some(optimistic);
+        self
+    }
+
+    /// Must be `light`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    pub fn platform<T: Into<String>>(mut self, platform: T) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
+    /// The maximum QoS level to be used when receiving and publishing messages.
+    pub fn qos(mut self, qos: Qos) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract red color from the state payload value. Expected result of the template is an integer from 0-255 range.
+    pub fn red_template<T: Into<String>>(mut self, red_template: T) -> Self {
+        self.red_template = Some(red_template.into());
+        self
+    }
+
+    /// [Template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract state from the state payload value.
+    pub fn state_template<T: Into<String>>(mut self, state_template: T) -> Self {
+        self.state_template = Some(state_template.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive state updates. A "None" payload resets to an `unknown` state. An empty payload is ignored.
+    pub fn state_topic<T: Into<String>>(mut self, state_topic: T) -> Self {
+        self.state_topic = Some(state_topic.into());
+        self
+    }
+
+    /// An ID that uniquely identifies this light. If two lights have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
+    pub fn unique_id<T: Into<String>>(mut self, unique_id: T) -> Self {
+        self.unique_id = Some(unique_id.into());
+        self
+    }
+}
+
+impl Default for TemplateLight {
+    fn default() -> Self {
+        Self {
+            topic_prefix: Default::default(),
+            origin: Default::default(),
+            device: Default::default(),
+            entity_category: Default::default(),
+            availability: Default::default(),
+            blue_template: Default::default(),
+            brightness_template: Default::default(),
+            color_temp_template: Default::default(),
+            command_off_template: Default::default(),
+            command_on_template: Default::default(),
+            command_topic: Default::default(),
+            enabled_by_default: Default::default(),
+            encoding: Default::default(),
+            effect_list: Default::default(),
+            effect_template: Default::default(),
+            green_template: Default::default(),
+            icon: Default::default(),
+            json_attributes_template: Default::default(),
+            json_attributes_topic: Default::default(),
+            max_mireds: Default::default(),
+            min_mireds: Default::default(),
+            name: Default::default(),
+            object_id: Default::default(),
+            optimistic: Default::default(),
+            platform: "light".to_string(),
+            qos: Default::default(),
+            red_template: Default::default(),
+            schema: LightSchema::Template,
+            state_template: Default::default(),
+            state_topic: Default::default(),
+            unique_id: Default::default(),
+        }
+    }
+}
+
+impl From<TemplateLight> for Light {
+    fn from(value: TemplateLight) -> Self {
+        Light::Template(value)
+    }
+}
+
+impl From<TemplateLight> for Entity {
+    fn from(value: TemplateLight) -> Self {
+        Entity::Light(Light::Template(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn can_serialize_a_json_light() {
+        let light = JsonLight::default()
+            .unique_id("kitchen")
+            .command_topic("kitchen/light/set")
+            .brightness_scale(4095)
+            .supported_color_modes(vec![ColorMode::ColorTemp, ColorMode::Hs])
+            .effect_list(vec!["rainbow", "colorloop"]);
+        assert_json_eq!(
+            Entity::from(light).get_attributes().unwrap(),
+            json!({
+                "o": {"name": ""},
+                "dev": {},
+                "platform": "light",
+                "schema": "json",
+                "avty_mode": "all",
+                "avty": [],
+                "uniq_id": "kitchen",
+                "cmd_t": "kitchen/light/set",
+                "bri_scl": 4095,
+                "sup_clrm": ["color_temp", "hs"],
+                "fx_list": ["rainbow", "colorloop"]
+            })
+        );
+    }
+
+    #[test]
+    fn can_deserialize_a_light_according_to_its_schema() {
+        let basic: Light = serde_json::from_value(json!({"cmd_t": "light/set"})).unwrap();
+        assert_eq!(
+            basic,
+            Light::Basic(BasicLight::default().command_topic("light/set"))
+        );
+
+        let default: Light =
+            serde_json::from_value(json!({"schema": "default", "cmd_t": "light/set"})).unwrap();
+        assert_eq!(default.schema(), LightSchema::Basic);
+
+        let template: Light = serde_json::from_value(json!({
+            "schema": "template",
+            "cmd_t": "light/set",
+            "cmd_on_tpl": "on",
+            "cmd_off_tpl": "off"
+        }))
+        .unwrap();
+        assert_eq!(
+            template,
+            Light::Template(
+                TemplateLight::default()
+                    .command_topic("light/set")
+                    .command_on_template("on")
+                    .command_off_template("off")
+            )
+        );
+
+        assert!(serde_json::from_value::<Light>(json!({"schema": "xml"})).is_err());
+    }
+}
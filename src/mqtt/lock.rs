@@ -1,10 +1,12 @@
 use super::common::Qos;
 use super::common::{Availability, Device, EntityCategory, Origin};
 use crate::Entity;
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 
-///
-#[derive(Clone, Debug, PartialEq, Serialize)]
+/// MQTT discovery configuration of a `lock` entity.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/lock.mqtt/)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Lock {
     /// Replaces `~` with this value in any MQTT topic attribute.
     /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
@@ -12,11 +14,11 @@ pub struct Lock {
     pub topic_prefix: Option<String>,
 
     /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
-    #[serde(rename = "o")]
+    #[serde(rename = "o", alias = "origin")]
     pub origin: Origin,
 
     /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
-    #[serde(rename = "dev")]
+    #[serde(rename = "dev", alias = "device")]
     pub device: Device,
 
     /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
@@ -24,7 +26,11 @@ pub struct Lock {
     pub availability: Availability,
 
     /// The category of the entity. (optional, default: None)
-    #[serde(rename = "ent_cat", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_category: Option<EntityCategory>,
 
     /// A regular expression to validate a supplied code when it is set during the action to `open`, `lock` or `unlock` the MQTT lock.
@@ -32,35 +38,59 @@ pub struct Lock {
     pub code_format: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `command_topic`. The lock command template accepts the parameters `value` and `code`. The `value` parameter will contain the configured value for either `payload_open`, `payload_lock` or `payload_unlock`. The `code` parameter is set during the action to `open`, `lock` or `unlock` the MQTT lock and will be set `None` if no code was passed.
-    #[serde(rename = "cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "cmd_tpl",
+        alias = "command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the lock state.
-    #[serde(rename = "cmd_t")]
+    #[serde(rename = "cmd_t", alias = "command_topic")]
     pub command_topic: String,
 
     /// Flag which defines if the entity should be enabled when first added.
-    #[serde(rename = "en", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub enabled_by_default: Option<bool>,
 
     /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
-    #[serde(rename = "e", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub encoding: Option<String>,
 
     /// Picture URL for the entity.
-    #[serde(rename = "ent_pic", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_pic",
+        alias = "entity_picture",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_picture: Option<String>,
 
     /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
-    #[serde(rename = "ic", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
-    #[serde(rename = "json_attr_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_template: Option<String>,
 
     /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
-    #[serde(rename = "json_attr_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_topic: Option<String>,
 
     /// The name of the lock. Can be set to `null` if only the device name is relevant.
@@ -68,31 +98,55 @@ pub struct Lock {
     pub name: Option<String>,
 
     /// Used instead of `name` for automatic generation of `entity_id`
-    #[serde(rename = "obj_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub object_id: Option<String>,
 
     /// Flag that defines if lock works in optimistic mode.
-    #[serde(rename = "opt", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "opt",
+        alias = "optimistic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub optimistic: Option<bool>,
 
     /// The payload sent to the lock to lock it.
-    #[serde(rename = "pl_lock", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_lock",
+        alias = "payload_lock",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_lock: Option<String>,
 
     /// The payload sent to the lock to unlock it.
-    #[serde(rename = "pl_unlk", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_unlk",
+        alias = "payload_unlock",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_unlock: Option<String>,
 
     /// The payload sent to the lock to open it.
-    #[serde(rename = "pl_open", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_open",
+        alias = "payload_open",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_open: Option<String>,
 
     /// A special payload that resets the state to `unknown` when received on the `state_topic`.
-    #[serde(rename = "pl_rst", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_rst",
+        alias = "payload_reset",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_reset: Option<String>,
 
     /// Must be `lock`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
-    #[serde(rename = "platform")]
+    #[serde(rename = "platform", alias = "p")]
     pub platform: String,
 
     /// The maximum QoS level to be used when receiving and publishing messages.
@@ -100,39 +154,75 @@ pub struct Lock {
     pub qos: Option<Qos>,
 
     /// If the published message should have the retain flag on or not.
-    #[serde(rename = "ret", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub retain: Option<bool>,
 
     /// The payload sent to `state_topic` by the lock when it's jammed.
-    #[serde(rename = "stat_jam", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_jam",
+        alias = "state_jammed",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_jammed: Option<String>,
 
     /// The payload sent to `state_topic` by the lock when it's locked.
-    #[serde(rename = "stat_locked", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_locked",
+        alias = "state_locked",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_locked: Option<String>,
 
     /// The payload sent to `state_topic` by the lock when it's locking.
-    #[serde(rename = "stat_locking", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_locking",
+        alias = "state_locking",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_locking: Option<String>,
 
     /// The MQTT topic subscribed to receive state updates. It accepts states configured with `state_jammed`, `state_locked`, `state_unlocked`, `state_locking` or `state_unlocking`. A "None" payload resets to an `unknown` state. An empty payload is ignored.
-    #[serde(rename = "stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_t",
+        alias = "state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_topic: Option<String>,
 
     /// The payload sent to `state_topic` by the lock when it's unlocked.
-    #[serde(rename = "stat_unlocked", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_unlocked",
+        alias = "state_unlocked",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_unlocked: Option<String>,
 
     /// The payload sent to `state_topic` by the lock when it's unlocking.
-    #[serde(rename = "stat_unlocking", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "stat_unlocking",
+        alias = "state_unlocking",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub state_unlocking: Option<String>,
 
     /// An ID that uniquely identifies this lock. If two locks have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
-    #[serde(rename = "uniq_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub unique_id: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract a state value from the payload.
-    #[serde(rename = "val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "val_tpl",
+        alias = "value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub value_template: Option<String>,
 }
 
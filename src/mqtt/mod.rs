@@ -1,5 +1,10 @@
+pub mod abbreviations;
+pub mod climate_modes;
 pub mod common;
+pub mod device_class_units;
 pub mod device_classes;
+pub mod features;
+pub mod string_enum;
 pub mod units;
 
 pub mod alarm_control_panel;
@@ -15,7 +20,9 @@ pub mod fan;
 pub mod humidifier;
 pub mod image;
 pub mod lawn_mower;
+pub mod light;
 pub mod lock;
+pub mod notify;
 pub mod number;
 pub mod scene;
 pub mod select;
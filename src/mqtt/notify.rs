@@ -0,0 +1,283 @@
+use super::common::Qos;
+use super::common::{Availability, Device, EntityCategory, Origin};
+use crate::Entity;
+use serde_derive::{Deserialize, Serialize};
+
+/// MQTT discovery configuration of a `notify` entity.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/notify.mqtt/)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Notify {
+    /// Replaces `~` with this value in any MQTT topic attribute.
+    /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+    #[serde(rename = "~", skip_serializing_if = "Option::is_none")]
+    pub topic_prefix: Option<String>,
+
+    /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
+    #[serde(rename = "o", alias = "origin")]
+    pub origin: Origin,
+
+    /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
+    #[serde(rename = "dev", alias = "device")]
+    pub device: Device,
+
+    /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
+    #[serde(flatten)]
+    pub availability: Availability,
+
+    /// The category of the entity. (optional, default: None)
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_category: Option<EntityCategory>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `command_topic`.
+    #[serde(
+        rename = "cmd_tpl",
+        alias = "command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub command_template: Option<String>,
+
+    /// The MQTT topic to publish send message commands at.
+    #[serde(
+        rename = "cmd_t",
+        alias = "command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub command_topic: Option<String>,
+
+    /// Flag which defines if the entity should be enabled when first added.
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enabled_by_default: Option<bool>,
+
+    /// The encoding of the published messages.
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encoding: Option<String>,
+
+    /// Picture URL for the entity.
+    #[serde(
+        rename = "ent_pic",
+        alias = "entity_picture",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub entity_picture: Option<String>,
+
+    /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub json_attributes_template: Option<String>,
+
+    /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub json_attributes_topic: Option<String>,
+
+    /// The name to use when displaying this notify entity. Can be set to `null` if only the device name is relevant.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Used instead of `name` for automatic generation of `entity_id`
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub object_id: Option<String>,
+
+    /// Must be `notify`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    #[serde(rename = "platform", alias = "p")]
+    pub platform: String,
+
+    /// The maximum QoS level to be used when receiving and publishing messages.
+    #[serde(rename = "qos", skip_serializing_if = "Option::is_none")]
+    pub qos: Option<Qos>,
+
+    /// If the published message should have the retain flag on or not.
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub retain: Option<bool>,
+
+    /// An ID that uniquely identifies this notify entity. If two notify entities have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unique_id: Option<String>,
+}
+
+impl Notify {
+    /// Replaces `~` with this value in any MQTT topic attribute.
+    /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
+    pub fn topic_prefix<S: Into<String>>(mut self, topic_prefix: S) -> Self {
+        self.topic_prefix = Some(topic_prefix.into());
+        self
+    }
+
+    /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Information about the device this sensor is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/device_registry_index/). Only works when `unique_id` is set. At least one of identifiers or connections must be present to identify the device.
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = device;
+        self
+    }
+
+    /// The category of the entity. (optional, default: None)
+    pub fn entity_category(mut self, entity_category: EntityCategory) -> Self {
+        self.entity_category = Some(entity_category);
+        self
+    }
+
+    /// Defines how HA will check for entity availability.
+    pub fn availability(mut self, availability: Availability) -> Self {
+        self.availability = availability;
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to generate the payload to send to `command_topic`.
+    pub fn command_template<T: Into<String>>(mut self, command_template: T) -> Self {
+        self.command_template = Some(command_template.into());
+        self
+    }
+
+    /// The MQTT topic to publish send message commands at.
+    pub fn command_topic<T: Into<String>>(mut self, command_topic: T) -> Self {
+        self.command_topic = Some(command_topic.into());
+        self
+    }
+
+    /// Flag which defines if the entity should be enabled when first added.
+    pub fn enabled_by_default(mut self, enabled_by_default: bool) -> Self {
+        self.enabled_by_default = Some(enabled_by_default);
+        self
+    }
+
+    /// The encoding of the published messages.
+    pub fn encoding<T: Into<String>>(mut self, encoding: T) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// Picture URL for the entity.
+    pub fn entity_picture<T: Into<String>>(mut self, entity_picture: T) -> Self {
+        self.entity_picture = Some(entity_picture.into());
+        self
+    }
+
+    /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
+    pub fn icon<T: Into<String>>(mut self, icon: T) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
+    pub fn json_attributes_template<T: Into<String>>(
+        mut self,
+        json_attributes_template: T,
+    ) -> Self {
+        self.json_attributes_template = Some(json_attributes_template.into());
+        self
+    }
+
+    /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
+    pub fn json_attributes_topic<T: Into<String>>(mut self, json_attributes_topic: T) -> Self {
+        self.json_attributes_topic = Some(json_attributes_topic.into());
+        self
+    }
+
+    /// The name to use when displaying this notify entity. Can be set to `null` if only the device name is relevant.
+    pub fn name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Used instead of `name` for automatic generation of `entity_id`
+    pub fn object_id<T: Into<String>>(mut self, object_id: T) -> Self {
+        self.object_id = Some(object_id.into());
+        self
+    }
+
+    /// Must be `notify`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
+    pub fn platform<T: Into<String>>(mut self, platform: T) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
+    /// The maximum QoS level to be used when receiving and publishing messages.
+    pub fn qos(mut self, qos: Qos) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// If the published message should have the retain flag on or not.
+    pub fn retain(mut self, retain: bool) -> Self {
+        self.retain = Some(retain);
+        self
+    }
+
+    /// An ID that uniquely identifies this notify entity. If two notify entities have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
+    pub fn unique_id<T: Into<String>>(mut self, unique_id: T) -> Self {
+        self.unique_id = Some(unique_id.into());
+        self
+    }
+}
+
+impl Default for Notify {
+    fn default() -> Self {
+        Self {
+            topic_prefix: Default::default(),
+            origin: Default::default(),
+            device: Default::default(),
+            entity_category: Default::default(),
+            availability: Default::default(),
+            command_template: Default::default(),
+            command_topic: Default::default(),
+            enabled_by_default: Default::default(),
+            encoding: Default::default(),
+            entity_picture: Default::default(),
+            icon: Default::default(),
+            json_attributes_template: Default::default(),
+            json_attributes_topic: Default::default(),
+            name: Default::default(),
+            object_id: Default::default(),
+            platform: "notify".to_string(),
+            qos: Default::default(),
+            retain: Default::default(),
+            unique_id: Default::default(),
+        }
+    }
+}
+
+impl From<Notify> for Entity {
+    fn from(value: Notify) -> Self {
+        Entity::Notify(value)
+    }
+}
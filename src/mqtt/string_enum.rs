@@ -0,0 +1,174 @@
+use std::fmt::{Display, Formatter};
+
+/// Error raised when parsing a string which isn't one of the values of an enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseEnumError {
+    /// The name of the enum.
+    pub expected: &'static str,
+    /// The string which couldn't be parsed.
+    pub value: String,
+}
+
+impl Display for ParseEnumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown {} '{}'", self.expected, self.value)
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
+/// Declares an enum of unit variants serialized as strings, and derives from the `#[serde(rename)]`
+/// of its variants `as_str`, `all`, [`Display`] and [`FromStr`](std::str::FromStr) implementations.
+///
+/// An enum ending with an untagged `Other(String)` variant accepts any other string,
+/// its [`FromStr`](std::str::FromStr) implementation never failing.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[doc = $doc:literal])*
+                #[serde(rename = $value:literal)]
+                $variant:ident,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $name {
+            $(
+                $(#[doc = $doc])*
+                #[serde(rename = $value)]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// The value as serialized in the configuration.
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    $($name::$variant => $value,)*
+                }
+            }
+
+            /// Every value, in declaration order.
+            pub fn all() -> impl Iterator<Item = $name> {
+                [$($name::$variant),*].into_iter()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::mqtt::string_enum::ParseEnumError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value {
+                    $($value => Ok($name::$variant),)*
+                    _ => Err($crate::mqtt::string_enum::ParseEnumError {
+                        expected: stringify!($name),
+                        value: value.to_string(),
+                    }),
+                }
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[doc = $doc:literal])*
+                #[serde(rename = $value:literal)]
+                $variant:ident,
+            )*
+            #[serde(untagged)]
+            Other(String),
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $name {
+            $(
+                $(#[doc = $doc])*
+                #[serde(rename = $value)]
+                $variant,
+            )*
+            /// Any other value.
+            #[serde(untagged)]
+            Other(String),
+        }
+
+        impl $name {
+            /// The value as serialized in the configuration.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(value) => value,
+                }
+            }
+
+            /// Every well-known value, in declaration order.
+            pub fn all() -> impl Iterator<Item = $name> {
+                [$($name::$variant),*].into_iter()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(value.into())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Other(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                value.as_str().into()
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::mqtt::device_classes::{HomeassistantDeviceClass, SensorDeviceClass};
+
+    #[test]
+    fn can_parse_and_display_every_device_class() {
+        for device_class in SensorDeviceClass::all() {
+            let name = device_class.to_string();
+            assert_eq!(serde_json::to_value(&device_class).unwrap(), json!(name));
+            assert_eq!(name.parse(), Ok(device_class));
+        }
+        assert_eq!("enum".parse(), Ok(SensorDeviceClass::Enum));
+        assert_eq!(HomeassistantDeviceClass::all().count(), 0);
+        assert_eq!(
+            "toaster"
+                .parse::<SensorDeviceClass>()
+                .unwrap_err()
+                .to_string(),
+            "unknown SensorDeviceClass 'toaster'"
+        );
+    }
+}
@@ -1,6 +1,16 @@
-use serde_derive::Serialize;
+use rust_decimal::Decimal;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::Deserializer;
+use serde_derive::{Deserialize, Serialize};
+
+use super::string_enum::{string_enum, ParseEnumError};
 
 /// Units of measurement
+///
+/// `m` is both [`LengthUnit::Meters`] and [`TimeUnit::Months`]: it is always parsed as meters,
+/// a [`Unit::Time`] in months is serialized as `m` but read back as a length.
 #[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
@@ -31,372 +41,957 @@ pub enum Unit {
     SignalStrength(SignalStrengthUnit),
     Data(DataUnit),
     DataRateUnit(DataRateUnit),
+    /// Any other unit, such as a custom one.
+    Other(String),
 }
 
-/// Power units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum PowerUnit {
-    #[serde(rename = "W")]
-    Watt,
-    #[serde(rename = "kW")]
-    KiloWatt,
+impl Unit {
+    /// The unit as serialized in the configuration.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Unit::Power(unit) => unit.as_str(),
+            Unit::Volt(unit) => unit.as_str(),
+            Unit::Energy(unit) => unit.as_str(),
+            Unit::Electrical(unit) => unit.as_str(),
+            Unit::Angle(unit) => unit.as_str(),
+            Unit::Currency(unit) => unit.as_str(),
+            Unit::Temperature(unit) => unit.as_str(),
+            Unit::Time(unit) => unit.as_str(),
+            Unit::Length(unit) => unit.as_str(),
+            Unit::Frequency(unit) => unit.as_str(),
+            Unit::Pressure(unit) => unit.as_str(),
+            Unit::Volume(unit) => unit.as_str(),
+            Unit::VolumeFlowRate(unit) => unit.as_str(),
+            Unit::Area(unit) => unit.as_str(),
+            Unit::Mass(unit) => unit.as_str(),
+            Unit::Conductivity(unit) => unit.as_str(),
+            Unit::Light(unit) => unit.as_str(),
+            Unit::Uv(unit) => unit.as_str(),
+            Unit::Percentage(unit) => unit.as_str(),
+            Unit::Irradiation(unit) => unit.as_str(),
+            Unit::Precipitation(unit) => unit.as_str(),
+            Unit::Concentration(unit) => unit.as_str(),
+            Unit::Speed(unit) => unit.as_str(),
+            Unit::SignalStrength(unit) => unit.as_str(),
+            Unit::Data(unit) => unit.as_str(),
+            Unit::DataRateUnit(unit) => unit.as_str(),
+            Unit::Other(unit) => unit,
+        }
+    }
+
+    /// Every well-known unit, grouped by family.
+    pub fn all() -> impl Iterator<Item = Unit> {
+        PowerUnit::all()
+            .map(Unit::Power)
+            .chain(VoltUnit::all().map(Unit::Volt))
+            .chain(EnergyUnit::all().map(Unit::Energy))
+            .chain(ElectricalUnit::all().map(Unit::Electrical))
+            .chain(AngleUnit::all().map(Unit::Angle))
+            .chain(CurrencyUnit::all().map(Unit::Currency))
+            .chain(TempUnit::all().map(Unit::Temperature))
+            .chain(TimeUnit::all().map(Unit::Time))
+            .chain(LengthUnit::all().map(Unit::Length))
+            .chain(FrequencyUnit::all().map(Unit::Frequency))
+            .chain(PressureUnit::all().map(Unit::Pressure))
+            .chain(VolumeUnit::all().map(Unit::Volume))
+            .chain(VolumeFlowRateUnit::all().map(Unit::VolumeFlowRate))
+            .chain(AreaUnit::all().map(Unit::Area))
+            .chain(MassUnit::all().map(Unit::Mass))
+            .chain(ConductivityUnit::all().map(Unit::Conductivity))
+            .chain(LightUnit::all().map(Unit::Light))
+            .chain(UvUnit::all().map(Unit::Uv))
+            .chain(PercentageUnit::all().map(Unit::Percentage))
+            .chain(IrradiationUnit::all().map(Unit::Irradiation))
+            .chain(PrecipitationUnit::all().map(Unit::Precipitation))
+            .chain(ConcentrationUnit::all().map(Unit::Concentration))
+            .chain(SpeedUnit::all().map(Unit::Speed))
+            .chain(SignalStrengthUnit::all().map(Unit::SignalStrength))
+            .chain(DataUnit::all().map(Unit::Data))
+            .chain(DataRateUnit::all().map(Unit::DataRateUnit))
+    }
 }
 
-/// Volt unit
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum VoltUnit {
-    #[serde(rename = "V")]
-    Volt,
+impl Display for Unit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-/// Energy units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum EnergyUnit {
-    #[serde(rename = "Wh")]
-    WattHour,
-    #[serde(rename = "kWh")]
-    KiloWattHour,
+impl FromStr for Unit {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        // lengths come first so that the ambiguous `m` is read as meters rather than months
+        let unit = value
+            .parse()
+            .map(Unit::Length)
+            .or_else(|_| value.parse().map(Unit::Power))
+            .or_else(|_| value.parse().map(Unit::Volt))
+            .or_else(|_| value.parse().map(Unit::Energy))
+            .or_else(|_| value.parse().map(Unit::Electrical))
+            .or_else(|_| value.parse().map(Unit::Angle))
+            .or_else(|_| value.parse().map(Unit::Currency))
+            .or_else(|_| value.parse().map(Unit::Temperature))
+            .or_else(|_| value.parse().map(Unit::Time))
+            .or_else(|_| value.parse().map(Unit::Frequency))
+            .or_else(|_| value.parse().map(Unit::Pressure))
+            .or_else(|_| value.parse().map(Unit::Volume))
+            .or_else(|_| value.parse().map(Unit::VolumeFlowRate))
+            .or_else(|_| value.parse().map(Unit::Area))
+            .or_else(|_| value.parse().map(Unit::Mass))
+            .or_else(|_| value.parse().map(Unit::Conductivity))
+            .or_else(|_| value.parse().map(Unit::Light))
+            .or_else(|_| value.parse().map(Unit::Uv))
+            .or_else(|_| value.parse().map(Unit::Percentage))
+            .or_else(|_| value.parse().map(Unit::Irradiation))
+            .or_else(|_| value.parse().map(Unit::Precipitation))
+            .or_else(|_| value.parse().map(Unit::Concentration))
+            .or_else(|_| value.parse().map(Unit::Speed))
+            .or_else(|_| value.parse().map(Unit::SignalStrength))
+            .or_else(|_| value.parse().map(Unit::Data))
+            .or_else(|_| value.parse().map(Unit::DataRateUnit))
+            .unwrap_or_else(|_: ParseEnumError| Unit::Other(value.to_string()));
+        Ok(unit)
+    }
 }
 
-/// Electrical units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum ElectricalUnit {
-    #[serde(rename = "A")]
-    CurrentAmpere,
-    #[serde(rename = "VA")]
-    VoltAmpere,
+impl<'de> serde::Deserialize<'de> for Unit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Ok(unit) = String::deserialize(deserializer)?.parse();
+        Ok(unit)
+    }
 }
 
-/// Angle units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum AngleUnit {
-    #[serde(rename = "°")]
-    Degree,
+string_enum! {
+    /// Power units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum PowerUnit {
+        #[serde(rename = "W")]
+        Watt,
+        #[serde(rename = "kW")]
+        KiloWatt,
+    }
 }
 
-/// Currency units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum CurrencyUnit {
-    #[serde(rename = "€")]
-    Euro,
-    #[serde(rename = "$")]
-    Dollar,
-    #[serde(rename = "¢")]
-    Cent,
+string_enum! {
+    /// Volt unit
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum VoltUnit {
+        #[serde(rename = "V")]
+        Volt,
+    }
 }
 
-/// Temperature units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum TempUnit {
-    #[serde(rename = "°C")]
-    Celsius,
-    #[serde(rename = "°F")]
-    TempFahrenheit,
-    #[serde(rename = "K")]
-    TempKelvin,
+string_enum! {
+    /// Energy units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum EnergyUnit {
+        #[serde(rename = "Wh")]
+        WattHour,
+        #[serde(rename = "kWh")]
+        KiloWattHour,
+    }
 }
 
-/// Time units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum TimeUnit {
-    #[serde(rename = "μs")]
-    Microseconds,
-    #[serde(rename = "ms")]
-    Milliseconds,
-    #[serde(rename = "s")]
-    Seconds,
-    #[serde(rename = "min")]
-    Minutes,
-    #[serde(rename = "h")]
-    Hours,
-    #[serde(rename = "d")]
-    Days,
-    #[serde(rename = "w")]
-    Weeks,
-    #[serde(rename = "m")]
-    Months,
-    #[serde(rename = "y")]
-    Years,
-}
-
-/// Length units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum LengthUnit {
-    #[serde(rename = "mm")]
-    Millimeters,
-    #[serde(rename = "cm")]
-    Centimeters,
-    #[serde(rename = "m")]
-    Meters,
-    #[serde(rename = "km")]
-    Kilometers,
-
-    #[serde(rename = "in")]
-    Inches,
-    #[serde(rename = "ft")]
-    Feet,
-    #[serde(rename = "yd")]
-    Yard,
-    #[serde(rename = "mi")]
-    Miles,
-}
-
-/// Frequency units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum FrequencyUnit {
-    #[serde(rename = "Hz")]
-    Hertz,
-    #[serde(rename = "GHz")]
-    GigaHertz,
+string_enum! {
+    /// Electrical units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum ElectricalUnit {
+        #[serde(rename = "A")]
+        CurrentAmpere,
+        #[serde(rename = "VA")]
+        VoltAmpere,
+        #[serde(rename = "var")]
+        VoltAmpereReactive,
+        #[serde(rename = "kvar")]
+        KiloVoltAmpereReactive,
+    }
 }
 
-/// Pressure units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum PressureUnit {
-    #[serde(rename = "Pa")]
-    Pa,
-    #[serde(rename = "hPa")]
-    HPa,
-    #[serde(rename = "bar")]
-    Bar,
-    #[serde(rename = "mbar")]
-    MBar,
-    #[serde(rename = "inHg")]
-    InHg,
-    #[serde(rename = "psi")]
-    Psi,
-}
-
-/// Volume units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum VolumeUnit {
-    #[serde(rename = "L")]
-    Liters,
-    #[serde(rename = "mL")]
-    Milliliters,
-    #[serde(rename = "m³")]
-    CubicMeters,
-    #[serde(rename = "ft³")]
-    CubicFeet,
-
-    #[serde(rename = "gal")]
-    Gallons,
-    #[serde(rename = "fl. oz.")]
-    FluidOunce,
-}
-
-/// Volume Flow Rate units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum VolumeFlowRateUnit {
-    #[serde(rename = "m³/h")]
-    CubicMetersPerHour,
-    #[serde(rename = "ft³/m")]
-    CubicFeetPerMinute,
+string_enum! {
+    /// Angle units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum AngleUnit {
+        #[serde(rename = "°")]
+        Degree,
+    }
 }
-/// Area units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum AreaUnit {
-    #[serde(rename = "m²")]
-    SquareMeters,
+
+string_enum! {
+    /// Currency units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum CurrencyUnit {
+        #[serde(rename = "€")]
+        Euro,
+        #[serde(rename = "$")]
+        Dollar,
+        #[serde(rename = "¢")]
+        Cent,
+    }
 }
 
-/// Mass units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum MassUnit {
-    #[serde(rename = "g")]
-    Grams,
-    #[serde(rename = "kg")]
-    Kilograms,
-    #[serde(rename = "mg")]
-    Milligrams,
-    #[serde(rename = "µg")]
-    Micrograms,
-
-    #[serde(rename = "oz")]
-    Ounces,
-    #[serde(rename = "lb")]
-    Pounds,
-}
-
-/// Conductivity units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum ConductivityUnit {
-    #[serde(rename = "µS/cm")]
-    Conductivity,
+string_enum! {
+    /// Temperature units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum TempUnit {
+        #[serde(rename = "°C")]
+        Celsius,
+        #[serde(rename = "°F")]
+        TempFahrenheit,
+        #[serde(rename = "K")]
+        TempKelvin,
+    }
 }
 
-/// Light units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum LightUnit {
-    #[serde(rename = "lx")]
-    Lux,
+string_enum! {
+    /// Time units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum TimeUnit {
+        #[serde(rename = "μs")]
+        Microseconds,
+        #[serde(rename = "ms")]
+        Milliseconds,
+        #[serde(rename = "s")]
+        Seconds,
+        #[serde(rename = "min")]
+        Minutes,
+        #[serde(rename = "h")]
+        Hours,
+        #[serde(rename = "d")]
+        Days,
+        #[serde(rename = "w")]
+        Weeks,
+        /// Read as [`LengthUnit::Meters`] when parsed as a [`Unit`].
+        #[serde(rename = "m")]
+        Months,
+        #[serde(rename = "y")]
+        Years,
+    }
 }
 
-/// UV Index units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum UvUnit {
-    #[serde(rename = "UV index")]
-    UvIndex,
+string_enum! {
+    /// Length units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum LengthUnit {
+        #[serde(rename = "mm")]
+        Millimeters,
+        #[serde(rename = "cm")]
+        Centimeters,
+        #[serde(rename = "m")]
+        Meters,
+        #[serde(rename = "km")]
+        Kilometers,
+
+        #[serde(rename = "in")]
+        Inches,
+        #[serde(rename = "ft")]
+        Feet,
+        #[serde(rename = "yd")]
+        Yard,
+        #[serde(rename = "mi")]
+        Miles,
+    }
 }
 
-/// Percentage units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum PercentageUnit {
-    #[serde(rename = "%")]
-    Percentage,
+string_enum! {
+    /// Frequency units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum FrequencyUnit {
+        #[serde(rename = "Hz")]
+        Hertz,
+        #[serde(rename = "GHz")]
+        GigaHertz,
+    }
 }
 
-/// Irradiation units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum IrradiationUnit {
-    #[serde(rename = "W/m²")]
-    WattsPerSquareMeter,
+string_enum! {
+    /// Pressure units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum PressureUnit {
+        #[serde(rename = "Pa")]
+        Pa,
+        #[serde(rename = "hPa")]
+        HPa,
+        #[serde(rename = "bar")]
+        Bar,
+        #[serde(rename = "mbar")]
+        MBar,
+        #[serde(rename = "inHg")]
+        InHg,
+        #[serde(rename = "psi")]
+        Psi,
+    }
 }
 
-/// Precipitation units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum PrecipitationUnit {
-    #[serde(rename = "mm/h")]
-    MillimetersPerHour,
+string_enum! {
+    /// Volume units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum VolumeUnit {
+        #[serde(rename = "L")]
+        Liters,
+        #[serde(rename = "mL")]
+        Milliliters,
+        #[serde(rename = "m³")]
+        CubicMeters,
+        #[serde(rename = "ft³")]
+        CubicFeet,
+
+        #[serde(rename = "gal")]
+        Gallons,
+        #[serde(rename = "fl. oz.")]
+        FluidOunce,
+    }
 }
 
-/// Concentration units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum ConcentrationUnit {
-    #[serde(rename = "µg/m³")]
-    MicrogramsPerCubicMeter,
-    #[serde(rename = "mg/m³")]
-    MilligramsPerCubicMeter,
-    #[serde(rename = "p/m³")]
-    PartsPerCubicMeter,
-    #[serde(rename = "ppm")]
-    PartsPerMillion,
-    #[serde(rename = "ppb")]
-    PartsPerBillion,
-}
-
-/// Speed units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum SpeedUnit {
-    #[serde(rename = "mm/d")]
-    MillimetersPerDay,
-    #[serde(rename = "in/d")]
-    InchesPerDay,
-    #[serde(rename = "m/s")]
-    MetersPerSecond,
-    #[serde(rename = "in/h")]
-    InchesPerHour,
-    #[serde(rename = "km/h")]
-    KilometersPerHour,
-    #[serde(rename = "mph")]
-    MilesPerHour,
-}
-
-/// Signal_strength units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum SignalStrengthUnit {
-    #[serde(rename = "dB")]
-    Decibels,
-    #[serde(rename = "dBm")]
-    DecibelsMilliwatt,
+string_enum! {
+    /// Volume Flow Rate units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum VolumeFlowRateUnit {
+        #[serde(rename = "m³/h")]
+        CubicMetersPerHour,
+        #[serde(rename = "ft³/m")]
+        CubicFeetPerMinute,
+    }
+}
+string_enum! {
+    /// Area units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum AreaUnit {
+        #[serde(rename = "m²")]
+        SquareMeters,
+    }
 }
 
-/// Data units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum DataUnit {
-    #[serde(rename = "bit")]
-    Bits,
-    #[serde(rename = "kbit")]
-    Kilobits,
-    #[serde(rename = "Mbit")]
-    Megabits,
-    #[serde(rename = "Gbit")]
-    Gigabits,
-    #[serde(rename = "B")]
-    Bytes,
-    #[serde(rename = "kB")]
-    Kilobytes,
-    #[serde(rename = "MB")]
-    Megabytes,
-    #[serde(rename = "GB")]
-    Gigabytes,
-    #[serde(rename = "TB")]
-    Terabytes,
-    #[serde(rename = "PB")]
-    Petabytes,
-    #[serde(rename = "EB")]
-    Exabytes,
-    #[serde(rename = "ZB")]
-    Zettabytes,
-    #[serde(rename = "YB")]
-    Yottabytes,
-    #[serde(rename = "KiB")]
-    Kibibytes,
-    #[serde(rename = "MiB")]
-    Mebibytes,
-    #[serde(rename = "GiB")]
-    Gibibytes,
-    #[serde(rename = "TiB")]
-    Tebibytes,
-    #[serde(rename = "PiB")]
-    Pebibytes,
-    #[serde(rename = "EiB")]
-    Exbibytes,
-    #[serde(rename = "ZiB")]
-    Zebibytes,
-    #[serde(rename = "YiB")]
-    Yobibytes,
-}
-
-/// Data rate units
-#[allow(dead_code)]
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub enum DataRateUnit {
-    #[serde(rename = "bit/s")]
-    BitsPerSecond,
-    #[serde(rename = "kbit/s")]
-    KilobitsPerSecond,
-    #[serde(rename = "Mbit/s")]
-    MegabitsPerSecond,
-    #[serde(rename = "Gbit/s")]
-    GigabitsPerSecond,
-    #[serde(rename = "B/s")]
-    BytesPerSecond,
-    #[serde(rename = "kB/s")]
-    KilobytesPerSecond,
-    #[serde(rename = "MB/s")]
-    MegabytesPerSecond,
-    #[serde(rename = "GB/s")]
-    GigabytesPerSecond,
-    #[serde(rename = "KiB/s")]
-    KibibytesPerSecond,
-    #[serde(rename = "MiB/s")]
-    MebibytesPerSecond,
-    #[serde(rename = "GiB/s")]
-    GibibytesPerSecond,
+string_enum! {
+    /// Mass units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum MassUnit {
+        #[serde(rename = "g")]
+        Grams,
+        #[serde(rename = "kg")]
+        Kilograms,
+        #[serde(rename = "mg")]
+        Milligrams,
+        #[serde(rename = "µg")]
+        Micrograms,
+
+        #[serde(rename = "oz")]
+        Ounces,
+        #[serde(rename = "lb")]
+        Pounds,
+    }
+}
+
+string_enum! {
+    /// Conductivity units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum ConductivityUnit {
+        #[serde(rename = "µS/cm")]
+        Conductivity,
+    }
+}
+
+string_enum! {
+    /// Light units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum LightUnit {
+        #[serde(rename = "lx")]
+        Lux,
+    }
+}
+
+string_enum! {
+    /// UV Index units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum UvUnit {
+        #[serde(rename = "UV index")]
+        UvIndex,
+    }
+}
+
+string_enum! {
+    /// Percentage units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum PercentageUnit {
+        #[serde(rename = "%")]
+        Percentage,
+    }
+}
+
+string_enum! {
+    /// Irradiation units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum IrradiationUnit {
+        #[serde(rename = "W/m²")]
+        WattsPerSquareMeter,
+    }
+}
+
+string_enum! {
+    /// Precipitation units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum PrecipitationUnit {
+        #[serde(rename = "mm/h")]
+        MillimetersPerHour,
+    }
+}
+
+string_enum! {
+    /// Concentration units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum ConcentrationUnit {
+        #[serde(rename = "µg/m³")]
+        MicrogramsPerCubicMeter,
+        #[serde(rename = "mg/m³")]
+        MilligramsPerCubicMeter,
+        #[serde(rename = "p/m³")]
+        PartsPerCubicMeter,
+        #[serde(rename = "ppm")]
+        PartsPerMillion,
+        #[serde(rename = "ppb")]
+        PartsPerBillion,
+    }
+}
+
+string_enum! {
+    /// Speed units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum SpeedUnit {
+        #[serde(rename = "mm/d")]
+        MillimetersPerDay,
+        #[serde(rename = "in/d")]
+        InchesPerDay,
+        #[serde(rename = "m/s")]
+        MetersPerSecond,
+        #[serde(rename = "in/h")]
+        InchesPerHour,
+        #[serde(rename = "km/h")]
+        KilometersPerHour,
+        #[serde(rename = "mph")]
+        MilesPerHour,
+    }
+}
+
+string_enum! {
+    /// Signal_strength units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum SignalStrengthUnit {
+        #[serde(rename = "dB")]
+        Decibels,
+        #[serde(rename = "dBm")]
+        DecibelsMilliwatt,
+    }
+}
+
+string_enum! {
+    /// Data units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum DataUnit {
+        #[serde(rename = "bit")]
+        Bits,
+        #[serde(rename = "kbit")]
+        Kilobits,
+        #[serde(rename = "Mbit")]
+        Megabits,
+        #[serde(rename = "Gbit")]
+        Gigabits,
+        #[serde(rename = "B")]
+        Bytes,
+        #[serde(rename = "kB")]
+        Kilobytes,
+        #[serde(rename = "MB")]
+        Megabytes,
+        #[serde(rename = "GB")]
+        Gigabytes,
+        #[serde(rename = "TB")]
+        Terabytes,
+        #[serde(rename = "PB")]
+        Petabytes,
+        #[serde(rename = "EB")]
+        Exabytes,
+        #[serde(rename = "ZB")]
+        Zettabytes,
+        #[serde(rename = "YB")]
+        Yottabytes,
+        #[serde(rename = "KiB")]
+        Kibibytes,
+        #[serde(rename = "MiB")]
+        Mebibytes,
+        #[serde(rename = "GiB")]
+        Gibibytes,
+        #[serde(rename = "TiB")]
+        Tebibytes,
+        #[serde(rename = "PiB")]
+        Pebibytes,
+        #[serde(rename = "EiB")]
+        Exbibytes,
+        #[serde(rename = "ZiB")]
+        Zebibytes,
+        #[serde(rename = "YiB")]
+        Yobibytes,
+    }
+}
+
+string_enum! {
+    /// Data rate units
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum DataRateUnit {
+        #[serde(rename = "bit/s")]
+        BitsPerSecond,
+        #[serde(rename = "kbit/s")]
+        KilobitsPerSecond,
+        #[serde(rename = "Mbit/s")]
+        MegabitsPerSecond,
+        #[serde(rename = "Gbit/s")]
+        GigabitsPerSecond,
+        #[serde(rename = "B/s")]
+        BytesPerSecond,
+        #[serde(rename = "kB/s")]
+        KilobytesPerSecond,
+        #[serde(rename = "MB/s")]
+        MegabytesPerSecond,
+        #[serde(rename = "GB/s")]
+        GigabytesPerSecond,
+        #[serde(rename = "KiB/s")]
+        KibibytesPerSecond,
+        #[serde(rename = "MiB/s")]
+        MebibytesPerSecond,
+        #[serde(rename = "GiB/s")]
+        GibibytesPerSecond,
+    }
+}
+
+/// Errors raised while converting a value between units.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConversionError {
+    /// The units measure different quantities, such as a temperature and an energy.
+    IncompatibleUnits { from: Unit, to: Unit },
+    /// The converted value doesn't fit in a decimal.
+    Overflow,
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::IncompatibleUnits { from, to } => {
+                write!(f, "cannot convert {from} to {to}")
+            }
+            ConversionError::Overflow => write!(f, "converted value overflows a decimal"),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// Quantities measured by the units, values being convertible between units of the same quantity.
+#[derive(PartialEq)]
+enum Quantity {
+    Power,
+    Voltage,
+    Energy,
+    Current,
+    ApparentPower,
+    ReactivePower,
+    Angle,
+    Dollar,
+    Euro,
+    Time,
+    Length,
+    Frequency,
+    Pressure,
+    Volume,
+    VolumeFlowRate,
+    Area,
+    Mass,
+    Conductivity,
+    Illuminance,
+    Uv,
+    Percentage,
+    Irradiance,
+    Speed,
+    MassConcentration,
+    ParticleConcentration,
+    Ratio,
+    Decibel,
+    DecibelMilliwatt,
+    Data,
+    DataRate,
+}
+
+impl Unit {
+    /// The quantity measured by the unit, and the value of the unit in the base unit of the quantity.
+    /// Temperatures aren't proportional, they are converted through Celsius degrees instead.
+    fn scale(&self) -> Option<(Quantity, Decimal)> {
+        let scale = match self {
+            Unit::Power(unit) => match unit {
+                PowerUnit::Watt => (Quantity::Power, Decimal::ONE),
+                PowerUnit::KiloWatt => (Quantity::Power, Decimal::ONE_THOUSAND),
+            },
+            Unit::Volt(VoltUnit::Volt) => (Quantity::Voltage, Decimal::ONE),
+            Unit::Energy(unit) => match unit {
+                EnergyUnit::WattHour => (Quantity::Energy, Decimal::ONE),
+                EnergyUnit::KiloWattHour => (Quantity::Energy, Decimal::ONE_THOUSAND),
+            },
+            Unit::Electrical(unit) => match unit {
+                ElectricalUnit::CurrentAmpere => (Quantity::Current, Decimal::ONE),
+                ElectricalUnit::VoltAmpere => (Quantity::ApparentPower, Decimal::ONE),
+                ElectricalUnit::VoltAmpereReactive => (Quantity::ReactivePower, Decimal::ONE),
+                ElectricalUnit::KiloVoltAmpereReactive => {
+                    (Quantity::ReactivePower, Decimal::ONE_THOUSAND)
+                }
+            },
+            Unit::Angle(AngleUnit::Degree) => (Quantity::Angle, Decimal::ONE),
+            // exchange rates vary, only dollars and cents are convertible
+            Unit::Currency(unit) => match unit {
+                CurrencyUnit::Euro => (Quantity::Euro, Decimal::ONE),
+                CurrencyUnit::Dollar => (Quantity::Dollar, Decimal::ONE_HUNDRED),
+                CurrencyUnit::Cent => (Quantity::Dollar, Decimal::ONE),
+            },
+            Unit::Temperature(_) | Unit::Other(_) => return None,
+            Unit::Time(unit) => (
+                Quantity::Time,
+                match unit {
+                    TimeUnit::Microseconds => Decimal::ONE,
+                    TimeUnit::Milliseconds => Decimal::from(1_000),
+                    TimeUnit::Seconds => Decimal::from(1_000_000),
+                    TimeUnit::Minutes => Decimal::from(60_000_000),
+                    TimeUnit::Hours => Decimal::from(3_600_000_000i64),
+                    TimeUnit::Days => Decimal::from(86_400_000_000i64),
+                    TimeUnit::Weeks => Decimal::from(604_800_000_000i64),
+                    // a month and a year of the Gregorian calendar on average
+                    TimeUnit::Months => Decimal::from(2_629_746_000_000i64),
+                    TimeUnit::Years => Decimal::from(31_556_952_000_000i64),
+                },
+            ),
+            Unit::Length(unit) => (
+                Quantity::Length,
+                match unit {
+                    LengthUnit::Millimeters => Decimal::ONE,
+                    LengthUnit::Centimeters => Decimal::TEN,
+                    LengthUnit::Meters => Decimal::ONE_THOUSAND,
+                    LengthUnit::Kilometers => Decimal::from(1_000_000),
+                    LengthUnit::Inches => Decimal::new(254, 1),
+                    LengthUnit::Feet => Decimal::new(3048, 1),
+                    LengthUnit::Yard => Decimal::new(9144, 1),
+                    LengthUnit::Miles => Decimal::from(1_609_344),
+                },
+            ),
+            Unit::Frequency(unit) => match unit {
+                FrequencyUnit::Hertz => (Quantity::Frequency, Decimal::ONE),
+                FrequencyUnit::GigaHertz => (Quantity::Frequency, Decimal::from(1_000_000_000)),
+            },
+            Unit::Pressure(unit) => (
+                Quantity::Pressure,
+                match unit {
+                    PressureUnit::Pa => Decimal::ONE,
+                    PressureUnit::HPa => Decimal::ONE_HUNDRED,
+                    PressureUnit::Bar => Decimal::from(100_000),
+                    PressureUnit::MBar => Decimal::ONE_HUNDRED,
+                    PressureUnit::InHg => Decimal::new(3_386_389, 3),
+                    PressureUnit::Psi => Decimal::new(6_894_757, 3),
+                },
+            ),
+            Unit::Volume(unit) => (
+                Quantity::Volume,
+                match unit {
+                    VolumeUnit::Milliliters => Decimal::ONE,
+                    VolumeUnit::Liters => Decimal::ONE_THOUSAND,
+                    VolumeUnit::CubicMeters => Decimal::from(1_000_000),
+                    VolumeUnit::CubicFeet => Decimal::new(28_316_846_592, 6),
+                    VolumeUnit::Gallons => Decimal::new(3_785_411_784, 6),
+                    VolumeUnit::FluidOunce => Decimal::new(295_735_295_625, 10),
+                },
+            ),
+            Unit::VolumeFlowRate(unit) => match unit {
+                VolumeFlowRateUnit::CubicMetersPerHour => (Quantity::VolumeFlowRate, Decimal::ONE),
+                VolumeFlowRateUnit::CubicFeetPerMinute => {
+                    (Quantity::VolumeFlowRate, Decimal::new(169_901_079_552, 11))
+                }
+            },
+            Unit::Area(AreaUnit::SquareMeters) => (Quantity::Area, Decimal::ONE),
+            Unit::Mass(unit) => (
+                Quantity::Mass,
+                match unit {
+                    MassUnit::Micrograms => Decimal::ONE,
+                    MassUnit::Milligrams => Decimal::ONE_THOUSAND,
+                    MassUnit::Grams => Decimal::from(1_000_000),
+                    MassUnit::Kilograms => Decimal::from(1_000_000_000),
+                    MassUnit::Ounces => Decimal::new(28_349_523_125, 3),
+                    MassUnit::Pounds => Decimal::from(453_592_370),
+                },
+            ),
+            Unit::Conductivity(ConductivityUnit::Conductivity) => {
+                (Quantity::Conductivity, Decimal::ONE)
+            }
+            Unit::Light(LightUnit::Lux) => (Quantity::Illuminance, Decimal::ONE),
+            Unit::Uv(UvUnit::UvIndex) => (Quantity::Uv, Decimal::ONE),
+            Unit::Percentage(PercentageUnit::Percentage) => (Quantity::Percentage, Decimal::ONE),
+            Unit::Irradiation(IrradiationUnit::WattsPerSquareMeter) => {
+                (Quantity::Irradiance, Decimal::ONE)
+            }
+            // precipitation intensities are speeds as well
+            Unit::Precipitation(PrecipitationUnit::MillimetersPerHour) => {
+                (Quantity::Speed, Decimal::from(24))
+            }
+            Unit::Speed(unit) => (
+                Quantity::Speed,
+                match unit {
+                    SpeedUnit::MillimetersPerDay => Decimal::ONE,
+                    SpeedUnit::InchesPerDay => Decimal::new(254, 1),
+                    SpeedUnit::MetersPerSecond => Decimal::from(86_400_000),
+                    SpeedUnit::InchesPerHour => Decimal::new(6096, 1),
+                    SpeedUnit::KilometersPerHour => Decimal::from(24_000_000),
+                    SpeedUnit::MilesPerHour => Decimal::from(38_624_256),
+                },
+            ),
+            Unit::Concentration(unit) => match unit {
+                ConcentrationUnit::MicrogramsPerCubicMeter => {
+                    (Quantity::MassConcentration, Decimal::ONE)
+                }
+                ConcentrationUnit::MilligramsPerCubicMeter => {
+                    (Quantity::MassConcentration, Decimal::ONE_THOUSAND)
+                }
+                ConcentrationUnit::PartsPerCubicMeter => {
+                    (Quantity::ParticleConcentration, Decimal::ONE)
+                }
+                ConcentrationUnit::PartsPerBillion => (Quantity::Ratio, Decimal::ONE),
+                ConcentrationUnit::PartsPerMillion => (Quantity::Ratio, Decimal::ONE_THOUSAND),
+            },
+            Unit::SignalStrength(unit) => match unit {
+                SignalStrengthUnit::Decibels => (Quantity::Decibel, Decimal::ONE),
+                SignalStrengthUnit::DecibelsMilliwatt => (Quantity::DecibelMilliwatt, Decimal::ONE),
+            },
+            Unit::Data(unit) => (
+                Quantity::Data,
+                match unit {
+                    DataUnit::Bits => Decimal::ONE,
+                    DataUnit::Kilobits => Decimal::from(1_000u64),
+                    DataUnit::Megabits => Decimal::from(1_000_000u64),
+                    DataUnit::Gigabits => Decimal::from(1_000_000_000u64),
+                    DataUnit::Bytes => Decimal::from(8u64),
+                    DataUnit::Kilobytes => Decimal::from(8 * 1_000u64),
+                    DataUnit::Megabytes => Decimal::from(8 * 1_000u64.pow(2)),
+                    DataUnit::Gigabytes => Decimal::from(8 * 1_000u64.pow(3)),
+                    DataUnit::Terabytes => Decimal::from(8 * 1_000u64.pow(4)),
+                    DataUnit::Petabytes => Decimal::from(8 * 1_000u64.pow(5)),
+                    DataUnit::Exabytes => Decimal::from(8 * 1_000u128.pow(6)),
+                    DataUnit::Zettabytes => Decimal::from(8 * 1_000u128.pow(7)),
+                    DataUnit::Yottabytes => Decimal::from(8 * 1_000u128.pow(8)),
+                    DataUnit::Kibibytes => Decimal::from(8 * 1_024u64),
+                    DataUnit::Mebibytes => Decimal::from(8 * 1_024u64.pow(2)),
+                    DataUnit::Gibibytes => Decimal::from(8 * 1_024u64.pow(3)),
+                    DataUnit::Tebibytes => Decimal::from(8 * 1_024u64.pow(4)),
+                    DataUnit::Pebibytes => Decimal::from(8 * 1_024u64.pow(5)),
+                    DataUnit::Exbibytes => Decimal::from(8 * 1_024u128.pow(6)),
+                    DataUnit::Zebibytes => Decimal::from(8 * 1_024u128.pow(7)),
+                    DataUnit::Yobibytes => Decimal::from(8 * 1_024u128.pow(8)),
+                },
+            ),
+            Unit::DataRateUnit(unit) => (
+                Quantity::DataRate,
+                match unit {
+                    DataRateUnit::BitsPerSecond => Decimal::ONE,
+                    DataRateUnit::KilobitsPerSecond => Decimal::from(1_000u64),
+                    DataRateUnit::MegabitsPerSecond => Decimal::from(1_000_000u64),
+                    DataRateUnit::GigabitsPerSecond => Decimal::from(1_000_000_000u64),
+                    DataRateUnit::BytesPerSecond => Decimal::from(8u64),
+                    DataRateUnit::KilobytesPerSecond => Decimal::from(8 * 1_000u64),
+                    DataRateUnit::MegabytesPerSecond => Decimal::from(8 * 1_000u64.pow(2)),
+                    DataRateUnit::GigabytesPerSecond => Decimal::from(8 * 1_000u64.pow(3)),
+                    DataRateUnit::KibibytesPerSecond => Decimal::from(8 * 1_024u64),
+                    DataRateUnit::MebibytesPerSecond => Decimal::from(8 * 1_024u64.pow(2)),
+                    DataRateUnit::GibibytesPerSecond => Decimal::from(8 * 1_024u64.pow(3)),
+                },
+            ),
+        };
+        Some(scale)
+    }
+
+    /// Converts a value measured in this unit to another unit of the same quantity,
+    /// for example from `°C` to `°F` or from `kWh` to `Wh`.
+    pub fn convert(&self, value: Decimal, to: &Unit) -> Result<Decimal, ConversionError> {
+        let incompatible = || ConversionError::IncompatibleUnits {
+            from: self.clone(),
+            to: to.clone(),
+        };
+        let converted = match (self, to) {
+            (Unit::Temperature(from), Unit::Temperature(to)) => {
+                to.convert_celsius(from.as_celsius(value)?)
+            }
+            _ => {
+                let (from_quantity, from_scale) = self.scale().ok_or_else(incompatible)?;
+                let (to_quantity, to_scale) = to.scale().ok_or_else(incompatible)?;
+                if from_quantity != to_quantity {
+                    return Err(incompatible());
+                }
+                value
+                    .checked_mul(from_scale)
+                    .and_then(|value| value.checked_div(to_scale))
+            }
+        };
+        converted
+            .map(|value| value.normalize())
+            .ok_or(ConversionError::Overflow)
+    }
+}
+
+impl TempUnit {
+    fn as_celsius(&self, value: Decimal) -> Result<Decimal, ConversionError> {
+        let celsius = match self {
+            TempUnit::Celsius => Some(value),
+            TempUnit::TempFahrenheit => value
+                .checked_sub(Decimal::from(32))
+                .and_then(|value| value.checked_div(Decimal::new(18, 1))),
+            TempUnit::TempKelvin => value.checked_sub(Decimal::new(27315, 2)),
+        };
+        celsius.ok_or(ConversionError::Overflow)
+    }
+
+    fn convert_celsius(&self, celsius: Decimal) -> Option<Decimal> {
+        match self {
+            TempUnit::Celsius => Some(celsius),
+            TempUnit::TempFahrenheit => celsius
+                .checked_mul(Decimal::new(18, 1))
+                .and_then(|value| value.checked_add(Decimal::from(32))),
+            TempUnit::TempKelvin => celsius.checked_add(Decimal::new(27315, 2)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+    use crate::mqtt::sensor::Sensor;
+
+    #[test]
+    fn can_convert_within_a_quantity() {
+        let convert = |value, from: Unit, to: Unit| from.convert(value, &to).unwrap();
+        assert_eq!(
+            convert(
+                dec!(1.5),
+                Unit::Energy(EnergyUnit::KiloWattHour),
+                Unit::Energy(EnergyUnit::WattHour)
+            ),
+            dec!(1500)
+        );
+        assert_eq!(
+            convert(
+                dec!(100),
+                Unit::Temperature(TempUnit::Celsius),
+                Unit::Temperature(TempUnit::TempFahrenheit)
+            ),
+            dec!(212)
+        );
+        assert_eq!(
+            convert(
+                dec!(300),
+                Unit::Temperature(TempUnit::TempKelvin),
+                Unit::Temperature(TempUnit::Celsius)
+            ),
+            dec!(26.85)
+        );
+        assert_eq!(
+            convert(
+                dec!(1013.25),
+                Unit::Pressure(PressureUnit::HPa),
+                Unit::Pressure(PressureUnit::Pa)
+            ),
+            dec!(101325)
+        );
+        assert_eq!(
+            convert(
+                dec!(10),
+                Unit::Speed(SpeedUnit::MetersPerSecond),
+                Unit::Speed(SpeedUnit::KilometersPerHour)
+            ),
+            dec!(36)
+        );
+        assert_eq!(
+            convert(
+                dec!(1),
+                Unit::Data(DataUnit::Kibibytes),
+                Unit::Data(DataUnit::Bits)
+            ),
+            dec!(8192)
+        );
+    }
+
+    #[test]
+    fn can_parse_and_display_every_unit() {
+        for unit in Unit::all() {
+            let name = unit.to_string();
+            assert_eq!(serde_json::to_value(&unit).unwrap(), json!(name));
+            let parsed: Unit = name.parse().unwrap();
+            assert_eq!(parsed.as_str(), name);
+            assert_eq!(serde_json::from_value::<Unit>(json!(name)).unwrap(), parsed);
+        }
+        assert_eq!("m".parse(), Ok(Unit::Length(LengthUnit::Meters)));
+        assert_eq!("kWh".parse(), Ok(EnergyUnit::KiloWattHour));
+        assert_eq!(
+            "parsec".parse::<Unit>(),
+            Ok(Unit::Other("parsec".to_string()))
+        );
+    }
+
+    #[test]
+    fn can_round_trip_a_custom_unit() {
+        let unit: Unit = serde_json::from_value(json!("pulses")).unwrap();
+        assert_eq!(unit, Unit::Other("pulses".to_string()));
+        assert_eq!(serde_json::to_value(&unit).unwrap(), json!("pulses"));
+        assert!(unit.convert(dec!(1), &unit).is_err());
+        let sensor: Sensor = serde_json::from_value(json!({"unit_of_meas": "pulses"})).unwrap();
+        assert_eq!(sensor.unit_of_measurement, Some(unit));
+
+        // months share their symbol with meters
+        let months = Unit::Time(TimeUnit::Months);
+        assert_eq!(
+            serde_json::from_value::<Unit>(serde_json::to_value(&months).unwrap()).unwrap(),
+            Unit::Length(LengthUnit::Meters)
+        );
+    }
+
+    #[test]
+    fn cannot_convert_across_quantities() {
+        let celsius = Unit::Temperature(TempUnit::Celsius);
+        let kwh = Unit::Energy(EnergyUnit::KiloWattHour);
+        assert_eq!(
+            celsius.convert(dec!(20), &kwh),
+            Err(ConversionError::IncompatibleUnits {
+                from: celsius.clone(),
+                to: kwh.clone()
+            })
+        );
+        assert!(Unit::Currency(CurrencyUnit::Euro)
+            .convert(dec!(1), &Unit::Currency(CurrencyUnit::Dollar))
+            .is_err());
+    }
 }
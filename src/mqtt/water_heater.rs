@@ -3,10 +3,12 @@ use super::common::TemperatureUnit;
 use super::common::{Availability, Device, EntityCategory, Origin};
 use crate::Entity;
 pub use rust_decimal::Decimal;
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 
-///
-#[derive(Clone, Debug, PartialEq, Serialize)]
+/// MQTT discovery configuration of a `water_heater` entity.
+/// [See Home Assistant documentation](https://www.home-assistant.io/integrations/water_heater.mqtt/)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct WaterHeater {
     /// Replaces `~` with this value in any MQTT topic attribute.
     /// [See Home Assistant documentation](https://www.home-assistant.io/integrations/mqtt/#using-abbreviations-and-base-topic)
@@ -14,11 +16,11 @@ pub struct WaterHeater {
     pub topic_prefix: Option<String>,
 
     /// It is encouraged to add additional information about the origin that supplies MQTT entities via MQTT discovery by adding the origin option (can be abbreviated to o) to the discovery payload. Note that these options also support abbreviations. Information of the origin will be logged to the core event log when an item is discovered or updated.
-    #[serde(rename = "o")]
+    #[serde(rename = "o", alias = "origin")]
     pub origin: Origin,
 
     /// Information about the device this button is a part of to tie it into the [device registry](https://developers.home-assistant.io/docs/en/device_registry_index.html). Only works when [`unique_id`](#unique_id) is set. At least one of identifiers or connections must be present to identify the device.
-    #[serde(rename = "dev")]
+    #[serde(rename = "dev", alias = "device")]
     pub device: Device,
 
     /// A list of MQTT topics subscribed to receive availability (online/offline) updates. Must not be used together with `availability_topic`.
@@ -26,43 +28,79 @@ pub struct WaterHeater {
     pub availability: Availability,
 
     /// The category of the entity. (optional, default: None)
-    #[serde(rename = "ent_cat", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_cat",
+        alias = "entity_category",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_category: Option<EntityCategory>,
 
     /// A template with which the value received on `current_temperature_topic` will be rendered.
-    #[serde(rename = "curr_temp_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "curr_temp_tpl",
+        alias = "current_temperature_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub current_temperature_template: Option<String>,
 
     /// The MQTT topic on which to listen for the current temperature. A `"None"` value received will reset the current temperature. Empty values (`'''`) will be ignored.
-    #[serde(rename = "curr_temp_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "curr_temp_t",
+        alias = "current_temperature_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub current_temperature_topic: Option<String>,
 
     /// Flag which defines if the entity should be enabled when first added.
-    #[serde(rename = "en", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "en",
+        alias = "enabled_by_default",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub enabled_by_default: Option<bool>,
 
     /// The encoding of the payloads received and published messages. Set to `""` to disable decoding of incoming payload.
-    #[serde(rename = "e", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "e",
+        alias = "encoding",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub encoding: Option<String>,
 
     /// Picture URL for the entity.
-    #[serde(rename = "ent_pic", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ent_pic",
+        alias = "entity_picture",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub entity_picture: Option<String>,
 
     /// Set the initial target temperature. The default value depends on the temperature unit, and will be 43.3°C or 110°F.
-    #[serde(rename = "init", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "init",
+        alias = "initial",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub initial: Option<i32>,
 
     /// [Icon](/docs/configuration/customizing-devices/#icon) for the entity.
-    #[serde(rename = "ic", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ic", alias = "icon", skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
 
     /// Defines a [template](/docs/configuration/templating/#using-templates-with-the-mqtt-integration) to extract the JSON dictionary from messages received on the `json_attributes_topic`. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-template-configuration) documentation.
-    #[serde(rename = "json_attr_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_tpl",
+        alias = "json_attributes_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_template: Option<String>,
 
     /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as sensor attributes. Usage example can be found in [MQTT sensor](/integrations/sensor.mqtt/#json-attributes-topic-configuration) documentation.
-    #[serde(rename = "json_attr_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "json_attr_t",
+        alias = "json_attributes_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub json_attributes_topic: Option<String>,
 
     /// Maximum set point available. The default value depends on the temperature unit, and will be 60°C or 140°F.
@@ -74,19 +112,35 @@ pub struct WaterHeater {
     pub min_temp: Option<Decimal>,
 
     /// A template to render the value sent to the `mode_command_topic` with.
-    #[serde(rename = "mode_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_cmd_tpl",
+        alias = "mode_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the water heater operation mode.
-    #[serde(rename = "mode_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_cmd_t",
+        alias = "mode_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_command_topic: Option<String>,
 
     /// A template to render the value received on the `mode_state_topic` with.
-    #[serde(rename = "mode_stat_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_stat_tpl",
+        alias = "mode_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_state_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes of the water heater operation mode. If this is not set, the operation mode works in optimistic mode (see below). A "None" payload resets to an `unknown` state. An empty payload is ignored.
-    #[serde(rename = "mode_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "mode_stat_t",
+        alias = "mode_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub mode_state_topic: Option<String>,
 
     /// A list of supported modes. Needs to be a subset of the default values.
@@ -98,23 +152,39 @@ pub struct WaterHeater {
     pub name: Option<String>,
 
     /// Used instead of `name` for automatic generation of `entity_id`
-    #[serde(rename = "obj_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "obj_id",
+        alias = "object_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub object_id: Option<String>,
 
     /// Flag that defines if the water heater works in optimistic mode
-    #[serde(rename = "opt", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "opt",
+        alias = "optimistic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub optimistic: Option<bool>,
 
     /// The payload that represents disabled state.
-    #[serde(rename = "pl_off", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_off",
+        alias = "payload_off",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_off: Option<String>,
 
     /// The payload that represents enabled state.
-    #[serde(rename = "pl_on", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "pl_on",
+        alias = "payload_on",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub payload_on: Option<String>,
 
     /// Must be `water_heater`. Only allowed and required in [MQTT auto discovery device messages](/integrations/mqtt/#device-discovery-payload).
-    #[serde(rename = "platform")]
+    #[serde(rename = "platform", alias = "p")]
     pub platform: String,
 
     /// A template to render the value sent to the `power_command_topic` with. The `value` parameter is the payload set for `payload_on` or `payload_off`.
@@ -140,35 +210,67 @@ pub struct WaterHeater {
     pub qos: Option<Qos>,
 
     /// Defines if published messages should have the retain flag set.
-    #[serde(rename = "ret", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "ret",
+        alias = "retain",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub retain: Option<bool>,
 
     /// A template to render the value sent to the `temperature_command_topic` with.
-    #[serde(rename = "temp_cmd_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_cmd_tpl",
+        alias = "temperature_command_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_command_template: Option<String>,
 
     /// The MQTT topic to publish commands to change the target temperature.
-    #[serde(rename = "temp_cmd_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_cmd_t",
+        alias = "temperature_command_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_command_topic: Option<String>,
 
     /// A template to render the value received on the `temperature_state_topic` with.
-    #[serde(rename = "temp_stat_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_stat_tpl",
+        alias = "temperature_state_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_state_template: Option<String>,
 
     /// The MQTT topic to subscribe for changes in the target temperature. If this is not set, the target temperature works in optimistic mode (see below). A `"None"` value received will reset the temperature set point. Empty values (`'''`) will be ignored.
-    #[serde(rename = "temp_stat_t", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_stat_t",
+        alias = "temperature_state_topic",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_state_topic: Option<String>,
 
     /// Defines the temperature unit of the device, `C` or `F`. If this is not set, the temperature unit is set to the system temperature unit.
-    #[serde(rename = "temp_unit", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "temp_unit",
+        alias = "temperature_unit",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub temperature_unit: Option<TemperatureUnit>,
 
     /// An ID that uniquely identifies this water heater device. If two water heater devices have the same unique ID, Home Assistant will raise an exception. Required when used with device-based discovery.
-    #[serde(rename = "uniq_id", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "uniq_id",
+        alias = "unique_id",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub unique_id: Option<String>,
 
     /// Default template to render the payloads on *all* `*_state_topic`s with.
-    #[serde(rename = "val_tpl", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "val_tpl",
+        alias = "value_template",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub value_template: Option<String>,
 }
 
@@ -0,0 +1,693 @@
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::device_discovery::DeviceDiscovery;
+use crate::mqtt::common::{Device, Qos};
+use crate::transport::{DiscoveryTransport, Message};
+use crate::validation::{Validate, ValidationError};
+use crate::{Entity, Style};
+
+const ONE_WEEK_SECONDS: u32 = 60 * 60 * 24 * 7;
+const DEFAULT_STATUS_TOPIC: &str = "homeassistant/status";
+const BIRTH_PAYLOAD: &[u8] = b"online";
+
+/// Errors raised while publishing to Home Assistant.
+#[derive(Debug)]
+pub enum PublishError<E> {
+    /// The entity or payload could not be serialized to JSON.
+    Serialization(serde_json::Error),
+    /// The entity configuration has no `uniq_id` attribute to build the discovery topic from.
+    MissingUniqueId,
+    /// The entity configuration breaks rules of Home Assistant, see [`Validate`].
+    Invalid(Vec<ValidationError>),
+    /// The topic receiving the state isn't configured on the entity, see [`crate::StatePublisher`].
+    MissingTopic(&'static str),
+    /// The state isn't allowed by the entity configuration, see [`crate::StatePublisher`].
+    InvalidState(String),
+    /// The MQTT client failed to publish the message.
+    Transport(E),
+}
+
+impl<E: Display> Display for PublishError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PublishError::Serialization(error) => write!(f, "serialization failed: {error}"),
+            PublishError::MissingUniqueId => {
+                write!(f, "entity configuration should have an attribute 'uniq_id'")
+            }
+            PublishError::Invalid(errors) => {
+                write!(f, "invalid configuration:")?;
+                for error in errors {
+                    write!(f, " {error};")?;
+                }
+                Ok(())
+            }
+            PublishError::MissingTopic(name) => {
+                write!(f, "entity configuration should have an attribute '{name}'")
+            }
+            PublishError::InvalidState(reason) => write!(f, "invalid state: {reason}"),
+            PublishError::Transport(error) => write!(f, "publication failed: {error}"),
+        }
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for PublishError<E> {}
+
+impl<E> From<serde_json::Error> for PublishError<E> {
+    fn from(value: serde_json::Error) -> Self {
+        PublishError::Serialization(value)
+    }
+}
+
+/// A configuration published on a discovery topic.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+enum Discovery {
+    Entity(Entity),
+    Device(DeviceDiscovery),
+}
+
+impl Discovery {
+    fn device(&self) -> &Device {
+        match self {
+            Discovery::Entity(entity) => entity.get_device(),
+            Discovery::Device(device_discovery) => &device_discovery.device,
+        }
+    }
+
+    fn qos(&self) -> Option<Qos> {
+        match self {
+            Discovery::Entity(entity) => entity.get_qos(),
+            Discovery::Device(device_discovery) => device_discovery.qos,
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        match self {
+            Discovery::Entity(entity) => entity.validate(),
+            Discovery::Device(device_discovery) => device_discovery.validate(),
+        }
+    }
+
+    fn payload(&self, style: Style) -> Result<Vec<u8>, serde_json::Error> {
+        let attributes = match self {
+            Discovery::Entity(entity) => entity.get_attributes()?,
+            Discovery::Device(device_discovery) => serde_json::to_value(device_discovery)?,
+        };
+        serde_json::to_vec(&style.apply(attributes))
+    }
+}
+
+/// Publishes entities configurations to Home Assistant through any [`DiscoveryTransport`].
+///
+/// Every published entity is remembered by its discovery topic until it is unpublished,
+/// so that all configurations can be published again when Home Assistant restarts.
+/// Clones share the same published entities.
+#[derive(Clone)]
+pub struct HomeAssistantMqtt<T> {
+    client: T,
+    discovery_prefix: String,
+    node_id: Option<String>,
+    status_topic: String,
+    style: Style,
+    validate: bool,
+    #[cfg(feature = "tokio")]
+    republish_delay: Option<Duration>,
+    entities: Arc<Mutex<BTreeMap<String, Discovery>>>,
+}
+
+impl<T: DiscoveryTransport> HomeAssistantMqtt<T> {
+    pub fn new<S: Into<String>>(client: T, discovery_prefix: S) -> Self {
+        Self {
+            client,
+            discovery_prefix: discovery_prefix.into(),
+            node_id: None,
+            status_topic: DEFAULT_STATUS_TOPIC.to_string(),
+            style: Style::default(),
+            validate: false,
+            #[cfg(feature = "tokio")]
+            republish_delay: None,
+            entities: Default::default(),
+        }
+    }
+
+    /// ID of the node providing the topic, inserted as `<node_id>` in the discovery topics.
+    /// The ID of the node must only consist of characters from the character class [a-zA-Z0-9_-] (alphanumerics, underscore and hyphen).
+    pub fn with_node_id<S: Into<String>>(mut self, node_id: S) -> Self {
+        self.node_id = Some(node_id.into());
+        self
+    }
+
+    /// The topic Home Assistant publishes its birth message `online` on when it starts. (default: `homeassistant/status`)
+    pub fn with_status_topic<S: Into<String>>(mut self, status_topic: S) -> Self {
+        self.status_topic = status_topic.into();
+        self
+    }
+
+    /// Naming of the attributes in the published configurations. (default: abbreviated)
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Refuses to publish configurations breaking rules of Home Assistant, see [`Validate`]. (default: false)
+    pub fn with_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Waits for a random duration up to `max_delay` before publishing the configurations again after the birth message,
+    /// so that many clients don't flood Home Assistant at the same time.
+    #[cfg(feature = "tokio")]
+    pub fn with_republish_delay(mut self, max_delay: Duration) -> Self {
+        self.republish_delay = Some(max_delay);
+        self
+    }
+
+    /// The MQTT client used to publish messages.
+    pub fn client(&self) -> &T {
+        &self.client
+    }
+
+    /// The discovery topic needs to follow a specific format:
+    /// `<discovery_prefix>/<component>/[<node_id>/]<object_id>/config`
+    ///
+    /// - `<discovery_prefix>`: The Discovery Prefix defaults to homeassistant. This prefix can be changed.
+    /// - `<component>`: One of the supported MQTT integrations, eg. binary_sensor.
+    /// - `<node_id>` (Optional): ID of the node providing the topic, this is not used by Home Assistant but may be used to structure the MQTT topic. The ID of the node must only consist of characters from the character class [a-zA-Z0-9_-] (alphanumerics, underscore and hyphen).
+    /// - `<object_id>`: The ID of the device. This is only to allow for separate topics for each device and is not used for the entity_id. The ID of the device must only consist of characters from the character class [a-zA-Z0-9_-] (alphanumerics, underscore and hyphen).
+    ///
+    /// The `<node_id>` level can be used by clients to only subscribe to their own (command) topics by using one wildcard topic like <discovery_prefix>/+/<node_id>/+/set.
+    ///
+    /// Best practice for entities with a unique_id is to set `<object_id>` to unique_id and omit the `<node_id>`.
+    /// Device triggers and tags, which have no unique_id, use the first identifier of their device
+    /// followed by the `type` and `subtype` of the trigger, or by the topic of the tag.
+    pub fn discovery_topic(&self, entity: &Entity) -> Result<String, PublishError<T::Error>> {
+        let object_id = match derived_object_id(entity, entity.get_device()) {
+            Some(object_id) => object_id,
+            None => entity
+                .get_attributes()?
+                .get("uniq_id")
+                .and_then(|unique_id| unique_id.as_str())
+                .ok_or(PublishError::MissingUniqueId)?
+                .to_string(),
+        };
+        Ok(self.topic(entity.get_component_name(), &object_id))
+    }
+
+    /// The discovery topic of a device-based discovery payload: `<discovery_prefix>/device/<object_id>/config`
+    pub fn device_discovery_topic(&self, device_discovery: &DeviceDiscovery) -> String {
+        format!(
+            "{}/device/{}/config",
+            self.prefix(),
+            device_discovery.object_id
+        )
+    }
+
+    fn prefix(&self) -> &str {
+        self.discovery_prefix
+            .strip_suffix('/')
+            .unwrap_or(&self.discovery_prefix)
+    }
+
+    fn topic(&self, component: &str, object_id: &str) -> String {
+        let prefix = self.prefix();
+        match &self.node_id {
+            Some(node_id) => format!("{prefix}/{component}/{node_id}/{object_id}/config"),
+            None => format!("{prefix}/{component}/{object_id}/config"),
+        }
+    }
+
+    /// Publishes the entity configuration as a retained message on its discovery topic.
+    /// See [`HomeAssistantMqtt::discovery_topic`] for the topic format.
+    pub async fn publish_entity<E: Into<Entity>>(
+        &self,
+        entity: E,
+    ) -> Result<(), PublishError<T::Error>> {
+        let entity = entity.into();
+        let topic = self.discovery_topic(&entity)?;
+        self.publish_discovery(topic, Discovery::Entity(entity))
+            .await
+    }
+
+    /// Publishes many components of a device in a single retained message on its device discovery topic.
+    /// See [`HomeAssistantMqtt::device_discovery_topic`] for the topic format.
+    pub async fn publish_device(
+        &self,
+        device_discovery: DeviceDiscovery,
+    ) -> Result<(), PublishError<T::Error>> {
+        let topic = self.device_discovery_topic(&device_discovery);
+        self.publish_discovery(topic, Discovery::Device(device_discovery))
+            .await
+    }
+
+    async fn publish_discovery(
+        &self,
+        topic: String,
+        discovery: Discovery,
+    ) -> Result<(), PublishError<T::Error>> {
+        if self.validate {
+            discovery.validate().map_err(PublishError::Invalid)?;
+        }
+        self.publish_config(topic.clone(), &discovery).await?;
+        self.entities.lock().unwrap().insert(topic, discovery);
+        Ok(())
+    }
+
+    async fn publish_config(
+        &self,
+        topic: String,
+        discovery: &Discovery,
+    ) -> Result<(), PublishError<T::Error>> {
+        let payload = discovery.payload(self.style)?;
+        let message = Message::new(topic, payload, true)
+            .qos(discovery.qos().unwrap_or(Qos::AtLeastOnce))
+            .content_type("application/json")
+            .message_expiry_interval(Some(ONE_WEEK_SECONDS));
+        self.client
+            .publish(message)
+            .await
+            .map_err(PublishError::Transport)
+    }
+
+    /// Publishes again the configuration of every entity published so far.
+    pub async fn republish_entities(&self) -> Result<(), PublishError<T::Error>> {
+        let entities: Vec<(String, Discovery)> = self
+            .entities
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(topic, discovery)| (topic.clone(), discovery.clone()))
+            .collect();
+        for (topic, discovery) in entities {
+            self.publish_config(topic, &discovery).await?;
+        }
+        Ok(())
+    }
+
+    /// Subscribes to the Home Assistant status topic, see [`HomeAssistantMqtt::with_status_topic`].
+    pub async fn subscribe_to_status(&self) -> Result<(), PublishError<T::Error>> {
+        self.client
+            .subscribe(self.status_topic.clone(), Qos::AtLeastOnce)
+            .await
+            .map_err(PublishError::Transport)
+    }
+
+    /// Handles a message received by the MQTT client.
+    /// When it is the Home Assistant birth message, every published configuration is published again
+    /// after the optional random delay.
+    ///
+    /// Returns whether the configurations have been published again.
+    pub async fn handle_message(
+        &self,
+        topic: &str,
+        payload: &[u8],
+    ) -> Result<bool, PublishError<T::Error>> {
+        if topic != self.status_topic || payload != BIRTH_PAYLOAD {
+            return Ok(false);
+        }
+        #[cfg(feature = "tokio")]
+        if let Some(max_delay) = self.republish_delay {
+            tokio::time::sleep(random_delay(max_delay)).await;
+        }
+        self.republish_entities().await?;
+        Ok(true)
+    }
+
+    /// Removes the entity from Home Assistant by publishing an empty retained payload on its discovery topic.
+    pub async fn unpublish_entity(&self, entity: &Entity) -> Result<(), PublishError<T::Error>> {
+        let topic = self.discovery_topic(entity)?;
+        self.clear_topic(topic).await
+    }
+
+    /// Removes the components of a device-based discovery payload from Home Assistant by publishing
+    /// an empty retained payload on its device discovery topic, computed as in [`HomeAssistantMqtt::publish_device`].
+    pub async fn unpublish_device(
+        &self,
+        device_discovery: &DeviceDiscovery,
+    ) -> Result<(), PublishError<T::Error>> {
+        let topic = self.device_discovery_topic(device_discovery);
+        self.clear_topic(topic).await
+    }
+
+    /// Removes every entity and device discovery payload published by this instance for this device from Home Assistant,
+    /// and returns the number of cleared discovery topics.
+    /// Devices are matched on shared identifiers or connections, see [`Device::matches`].
+    ///
+    /// Only the configurations published since the instance was created are known: after a restart,
+    /// use [`HomeAssistantMqtt::unpublish_entity`] and [`HomeAssistantMqtt::unpublish_device`] instead.
+    pub async fn unpublish_registered_device(
+        &self,
+        device: &Device,
+    ) -> Result<usize, PublishError<T::Error>> {
+        let topics: Vec<String> = self
+            .entities
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, discovery)| discovery.device().matches(device))
+            .map(|(topic, _)| topic.clone())
+            .collect();
+        let count = topics.len();
+        for topic in topics {
+            self.clear_topic(topic).await?;
+        }
+        Ok(count)
+    }
+
+    async fn clear_topic(&self, topic: String) -> Result<(), PublishError<T::Error>> {
+        self.client
+            .publish(Message::new(topic.clone(), Vec::new(), true))
+            .await
+            .map_err(PublishError::Transport)?;
+        self.entities.lock().unwrap().remove(&topic);
+        Ok(())
+    }
+
+    /// Publishes any serializable payload as a retained JSON message.
+    pub async fn publish_data<S: Serialize>(
+        &self,
+        topic: &str,
+        payload: &S,
+        message_expiry_interval: Option<u32>,
+    ) -> Result<(), PublishError<T::Error>> {
+        let payload = serde_json::to_vec(payload)?;
+        let message = Message::new(topic, payload, true)
+            .content_type("application/json")
+            .message_expiry_interval(message_expiry_interval);
+        self.client
+            .publish(message)
+            .await
+            .map_err(PublishError::Transport)
+    }
+}
+
+/// The object ID of the device triggers and tags, which have no unique_id: the first identifier or connection
+/// of the device followed by the `type` and `subtype` of the trigger, or by the topic of the tag,
+/// with the characters not allowed in object IDs replaced by `_`. `None` for the other entities.
+pub(crate) fn derived_object_id(entity: &Entity, device: &Device) -> Option<String> {
+    let parts = match entity {
+        Entity::DeviceTrigger(trigger) => vec![trigger.r#type.as_str(), trigger.subtype.as_str()],
+        Entity::Tag(tag) => vec![tag.topic.as_str()],
+        _ => return None,
+    };
+    let identifier = device.identifiers.first().or_else(|| {
+        device
+            .connections
+            .first()
+            .map(|connection| &connection.identifier)
+    });
+    let object_id = identifier
+        .map(String::as_str)
+        .into_iter()
+        .chain(parts)
+        .collect::<Vec<_>>()
+        .join("_")
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' => c,
+            _ => '_',
+        })
+        .collect();
+    Some(object_id)
+}
+
+#[cfg(feature = "tokio")]
+fn random_delay(max_delay: Duration) -> Duration {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let random = RandomState::new().build_hasher().finish();
+    max_delay.mul_f64((random % 1000) as f64 / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::mqtt::{
+        binary_sensor::BinarySensor, common::DeviceConnection, device_trigger::DeviceTrigger,
+        sensor::Sensor,
+    };
+    use crate::transport::testing::{block_on, RecordingTransport};
+
+    #[test]
+    fn can_publish_an_entity_on_its_discovery_topic() {
+        let transport = RecordingTransport::default();
+        let registry = HomeAssistantMqtt::new(transport.clone(), "homeassistant/");
+        block_on(
+            registry.publish_entity(
+                Sensor::default()
+                    .unique_id("barometer-09AF_temperature")
+                    .state_topic("~/state"),
+            ),
+        )
+        .unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        assert_eq!(
+            messages[0].topic,
+            "homeassistant/sensor/barometer-09AF_temperature/config"
+        );
+        assert!(messages[0].retain);
+        assert_eq!(messages[0].qos, Qos::AtLeastOnce);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&messages[0].payload).unwrap()["stat_t"],
+            json!("~/state")
+        );
+    }
+
+    #[test]
+    fn can_publish_configurations_with_full_names() {
+        let transport = RecordingTransport::default();
+        let registry =
+            HomeAssistantMqtt::new(transport.clone(), "homeassistant").with_style(Style::Full);
+        block_on(registry.publish_entity(Sensor::default().unique_id("temperature"))).unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        let payload = serde_json::from_slice::<serde_json::Value>(&messages[0].payload).unwrap();
+        assert_eq!(payload["unique_id"], json!("temperature"));
+        assert_eq!(payload.get("uniq_id"), None);
+    }
+
+    #[test]
+    fn can_refuse_to_publish_an_invalid_entity() {
+        let transport = RecordingTransport::default();
+        let registry =
+            HomeAssistantMqtt::new(transport.clone(), "homeassistant").with_validation(true);
+        let result = block_on(registry.publish_entity(Sensor::default().unique_id("temperature")));
+
+        assert!(
+            matches!(result, Err(PublishError::Invalid(errors)) if errors[0].path == "device.identifiers")
+        );
+        assert!(transport.messages.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn can_publish_an_entity_with_its_own_qos() {
+        let transport = RecordingTransport::default();
+        let registry = HomeAssistantMqtt::new(transport.clone(), "homeassistant");
+        block_on(
+            registry.publish_entity(
+                Sensor::default()
+                    .unique_id("temperature")
+                    .qos(Qos::ExactlyOnce),
+            ),
+        )
+        .unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        assert_eq!(messages[0].qos, Qos::ExactlyOnce);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&messages[0].payload).unwrap()["qos"],
+            json!(2)
+        );
+    }
+
+    #[test]
+    fn can_insert_a_node_id_in_the_discovery_topic() {
+        let registry = HomeAssistantMqtt::new(RecordingTransport::default(), "homeassistant")
+            .with_node_id("gateway");
+        let topic = registry
+            .discovery_topic(&Sensor::default().unique_id("temperature").into())
+            .unwrap();
+        assert_eq!(topic, "homeassistant/sensor/gateway/temperature/config");
+    }
+
+    #[test]
+    fn cannot_publish_an_entity_without_unique_id() {
+        let registry = HomeAssistantMqtt::new(RecordingTransport::default(), "homeassistant");
+        let result = block_on(registry.publish_entity(Sensor::default()));
+        assert!(matches!(result, Err(PublishError::MissingUniqueId)));
+    }
+
+    #[test]
+    fn can_publish_a_device_trigger_on_a_derived_topic() {
+        let transport = RecordingTransport::default();
+        let registry = HomeAssistantMqtt::new(transport.clone(), "homeassistant");
+        let trigger = DeviceTrigger::default()
+            .device(Device::default().add_identifier("0x90fd9ffffedf1266"))
+            .topic("zigbee2mqtt/remote/action")
+            .r#type("button_short_press")
+            .subtype("button 1")
+            .payload("arrow_left_click");
+        block_on(registry.publish_entity(trigger.clone())).unwrap();
+        block_on(registry.unpublish_entity(&trigger.into())).unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        let topic =
+            "homeassistant/device_automation/0x90fd9ffffedf1266_button_short_press_button_1/config";
+        assert_eq!(messages[0].topic, topic);
+        assert_eq!(messages[1].topic, topic);
+        assert!(messages[1].payload.is_empty());
+    }
+
+    #[test]
+    fn can_unpublish_an_entity_with_an_empty_retained_payload() {
+        let transport = RecordingTransport::default();
+        let registry = HomeAssistantMqtt::new(transport.clone(), "homeassistant");
+        block_on(registry.unpublish_entity(&Sensor::default().unique_id("temperature").into()))
+            .unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        assert_eq!(messages[0].topic, "homeassistant/sensor/temperature/config");
+        assert!(messages[0].payload.is_empty());
+        assert!(messages[0].retain);
+    }
+
+    #[test]
+    fn can_unpublish_every_entity_of_a_device() {
+        let transport = RecordingTransport::default();
+        let registry = HomeAssistantMqtt::new(transport.clone(), "homeassistant");
+        let device = Device::default()
+            .add_identifier("barometer-09AF")
+            .add_connection(DeviceConnection::mac("09:AF:A4:54:F0:9D"));
+        let cleared = block_on(async {
+            registry
+                .publish_entity(
+                    Sensor::default()
+                        .unique_id("temperature")
+                        .device(device.clone()),
+                )
+                .await?;
+            registry
+                .publish_entity(BinarySensor::default().unique_id("door").device(device))
+                .await?;
+            registry
+                .publish_entity(
+                    Sensor::default()
+                        .unique_id("humidity")
+                        .device(Device::default().add_identifier("hygrometer")),
+                )
+                .await?;
+            registry
+                .unpublish_registered_device(
+                    &Device::default().add_connection(DeviceConnection::mac("09:AF:A4:54:F0:9D")),
+                )
+                .await
+        })
+        .unwrap();
+        assert_eq!(cleared, 2);
+
+        let messages = transport.messages.lock().unwrap();
+        let topics: Vec<&str> = messages[3..]
+            .iter()
+            .filter(|message| message.payload.is_empty())
+            .map(|message| message.topic.as_str())
+            .collect();
+        assert_eq!(
+            topics,
+            vec![
+                "homeassistant/binary_sensor/door/config",
+                "homeassistant/sensor/temperature/config"
+            ]
+        );
+    }
+
+    #[test]
+    fn can_republish_every_entity_on_birth_message() {
+        let transport = RecordingTransport::default();
+        let registry = HomeAssistantMqtt::new(transport.clone(), "homeassistant")
+            .with_status_topic("ha/status");
+        let republished = block_on(async {
+            registry
+                .publish_entity(Sensor::default().unique_id("temperature"))
+                .await?;
+            registry
+                .publish_entity(BinarySensor::default().unique_id("door"))
+                .await?;
+            Ok::<_, PublishError<Infallible>>((
+                registry.handle_message("ha/status", b"offline").await?,
+                registry
+                    .handle_message("homeassistant/status", b"online")
+                    .await?,
+                registry.handle_message("ha/status", b"online").await?,
+            ))
+        })
+        .unwrap();
+
+        assert_eq!(republished, (false, false, true));
+        let messages = transport.messages.lock().unwrap();
+        let topics: Vec<&str> = messages[2..]
+            .iter()
+            .map(|message| message.topic.as_str())
+            .collect();
+        assert_eq!(
+            topics,
+            vec![
+                "homeassistant/binary_sensor/door/config",
+                "homeassistant/sensor/temperature/config"
+            ]
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn random_delay_is_bounded() {
+        let max_delay = std::time::Duration::from_secs(5);
+        assert!(random_delay(max_delay) <= max_delay);
+    }
+
+    #[test]
+    fn can_publish_and_unpublish_a_device_discovery_payload() {
+        let transport = RecordingTransport::default();
+        let registry = HomeAssistantMqtt::new(transport.clone(), "homeassistant/");
+        let device_discovery = DeviceDiscovery::new(
+            "thermostat-01",
+            Device::default().add_identifier("thermostat-01"),
+            Default::default(),
+        )
+        .add_component(Sensor::default().unique_id("temperature"));
+        let registered = block_on(async {
+            registry.publish_device(device_discovery.clone()).await?;
+            // a new instance, as after a restart, knows nothing of the published payload
+            let restarted = HomeAssistantMqtt::new(transport.clone(), "homeassistant");
+            restarted.unpublish_device(&device_discovery).await?;
+            restarted
+                .unpublish_registered_device(&device_discovery.device)
+                .await
+        })
+        .unwrap();
+        assert_eq!(registered, 0);
+
+        let messages = transport.messages.lock().unwrap();
+        assert_eq!(
+            messages[0].topic,
+            "homeassistant/device/thermostat-01/config"
+        );
+        assert!(messages[0].payload.starts_with(b"{"));
+        assert_eq!(
+            messages[1].topic,
+            "homeassistant/device/thermostat-01/config"
+        );
+        assert!(messages[1].payload.is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+//! Simulations of devices following the commands they receive the way Home Assistant assumes in optimistic mode,
+//! to test an integration without the actual device.
+
+use crate::command::{CommandError, EntityCommands, LawnMowerCommand};
+use crate::mqtt::lawn_mower::LawnMower;
+use crate::publisher::PublishError;
+use crate::state::{LawnMowerActivity, StatePublisher};
+use crate::transport::DiscoveryTransport;
+
+/// A lawn mower whose activity is the one Home Assistant assumes after each command in optimistic mode.
+#[derive(Clone, Debug)]
+pub struct OptimisticLawnMower {
+    entity: LawnMower,
+    activity: LawnMowerActivity,
+}
+
+impl OptimisticLawnMower {
+    /// A docked lawn mower.
+    pub fn new(entity: LawnMower) -> Self {
+        Self {
+            entity,
+            activity: LawnMowerActivity::Docked,
+        }
+    }
+
+    /// The configuration of the lawn mower.
+    pub fn entity(&self) -> &LawnMower {
+        &self.entity
+    }
+
+    /// The current activity of the lawn mower.
+    pub fn activity(&self) -> LawnMowerActivity {
+        self.activity
+    }
+
+    /// Applies a command, and returns whether the activity changed.
+    pub fn apply(&mut self, command: &LawnMowerCommand) -> bool {
+        let activity = match command {
+            LawnMowerCommand::StartMowing => LawnMowerActivity::Mowing,
+            LawnMowerCommand::Pause => LawnMowerActivity::Paused,
+            LawnMowerCommand::Dock => LawnMowerActivity::Docked,
+        };
+        let changed = self.activity != activity;
+        self.activity = activity;
+        changed
+    }
+
+    /// Decodes a received message and applies its command, returning whether the activity changed,
+    /// or returns `None` when the topic isn't one of the command topics of the lawn mower.
+    pub fn receive(&mut self, topic: &str, payload: &[u8]) -> Option<Result<bool, CommandError>> {
+        let command = self.entity.decode_command(topic, payload)?;
+        Some(command.map(|command| self.apply(&command)))
+    }
+
+    /// Publishes the current activity of the lawn mower on its `activity_state_topic`.
+    pub async fn publish_state<T: DiscoveryTransport>(
+        &self,
+        publisher: &StatePublisher<T>,
+    ) -> Result<(), PublishError<T::Error>> {
+        publisher.publish_state(&self.entity, &self.activity).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::testing::{block_on, RecordingTransport};
+
+    #[test]
+    fn can_follow_the_commands_of_home_assistant() {
+        let transport = RecordingTransport::default();
+        let publisher = StatePublisher::new(transport.clone());
+        let mut mower = OptimisticLawnMower::new(
+            LawnMower::default()
+                .topic_prefix("garden/mower")
+                .activity_state_topic("~/activity")
+                .start_mowing_command_topic("~/start")
+                .pause_command_topic("~/pause"),
+        );
+
+        assert_eq!(
+            mower.receive("garden/mower/start", b"start_mowing"),
+            Some(Ok(true))
+        );
+        assert_eq!(
+            mower.receive("garden/mower/start", b"start_mowing"),
+            Some(Ok(false))
+        );
+        assert_eq!(mower.activity(), LawnMowerActivity::Mowing);
+        block_on(mower.publish_state(&publisher)).unwrap();
+        assert_eq!(
+            mower.receive("garden/mower/pause", b"start_mowing"),
+            Some(Err(CommandError::UnknownPayload(
+                "start_mowing".to_string()
+            )))
+        );
+        assert_eq!(mower.receive("garden/mower/dock", b"dock"), None);
+
+        let messages = transport.messages.lock().unwrap();
+        assert_eq!(messages[0].topic, "garden/mower/activity");
+        assert_eq!(messages[0].payload, b"mowing");
+    }
+}
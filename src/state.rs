@@ -0,0 +1,740 @@
+//! Publication of the states of entities on the topics described by their configuration.
+
+use std::fmt::{Display, Formatter};
+
+use rust_decimal::Decimal;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[cfg(feature = "alarm")]
+use crate::alarm::AlarmState;
+use crate::discovery::resolve_topic;
+#[cfg(feature = "alarm")]
+use crate::mqtt::alarm_control_panel::AlarmControlPanel;
+use crate::mqtt::climate_modes::{ClimateAction, FanMode, HvacMode, PresetMode, SwingMode};
+use crate::mqtt::string_enum::string_enum;
+use crate::mqtt::{
+    binary_sensor::BinarySensor, climate::Climate, common::Qos, cover::Cover,
+    lawn_mower::LawnMower, lock::Lock, number::Number, select::Select, sensor::Sensor,
+    switch::Switch, text::Text, vacuum::Vacuum,
+};
+use crate::publisher::PublishError;
+use crate::transport::{DiscoveryTransport, Message};
+
+/// Errors raised when the state of an entity can't be published with its configuration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateError {
+    /// The topic receiving the state isn't configured on the entity.
+    MissingTopic(&'static str),
+    /// The state isn't allowed by the configuration, such as a fan speed missing from `fan_speed_list`.
+    InvalidState(String),
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::MissingTopic(name) => {
+                write!(f, "entity configuration should have an attribute '{name}'")
+            }
+            StateError::InvalidState(reason) => write!(f, "invalid state: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+impl<E> From<StateError> for PublishError<E> {
+    fn from(value: StateError) -> Self {
+        match value {
+            StateError::MissingTopic(name) => PublishError::MissingTopic(name),
+            StateError::InvalidState(reason) => PublishError::InvalidState(reason),
+        }
+    }
+}
+
+fn required<'a>(topic: &'a Option<String>, name: &'static str) -> Result<&'a str, StateError> {
+    non_empty(topic.as_deref().unwrap_or_default(), name)
+}
+
+/// The topic of a configuration attribute which is always present, empty when it isn't set.
+fn non_empty<'a>(topic: &'a str, name: &'static str) -> Result<&'a str, StateError> {
+    match topic {
+        "" => Err(StateError::MissingTopic(name)),
+        topic => Ok(topic),
+    }
+}
+
+/// Options of an entity applying to every state message.
+pub struct StateOptions<'a> {
+    /// The `~` base topic of the entity.
+    pub topic_prefix: Option<&'a str>,
+    /// The topic of the JSON attributes of the entity.
+    pub json_attributes_topic: Option<&'a str>,
+    /// The QoS level of the entity. (default: 0)
+    pub qos: Option<Qos>,
+    /// Whether the states are retained by the broker. (default: false)
+    pub retain: bool,
+}
+
+/// An entity whose state is published by the device on the topics of its configuration.
+pub trait EntityState {
+    /// The typed state of the entity, or one of its parts when it is published on several topics.
+    type State;
+
+    /// The topic, which may start or end with `~`, and the payload publishing the state.
+    fn state_payload(&self, state: &Self::State) -> Result<(&str, String), StateError>;
+
+    /// The options applying to every state message of the entity.
+    fn state_options(&self) -> StateOptions<'_>;
+}
+
+/// Implements `state_options`, with the `retain` attribute of the entity when it has one.
+macro_rules! state_options {
+    () => {
+        fn state_options(&self) -> StateOptions<'_> {
+            StateOptions {
+                topic_prefix: self.topic_prefix.as_deref(),
+                json_attributes_topic: self.json_attributes_topic.as_deref(),
+                qos: self.qos,
+                retain: false,
+            }
+        }
+    };
+    (retain) => {
+        fn state_options(&self) -> StateOptions<'_> {
+            StateOptions {
+                topic_prefix: self.topic_prefix.as_deref(),
+                json_attributes_topic: self.json_attributes_topic.as_deref(),
+                qos: self.qos,
+                retain: self.retain.unwrap_or(false),
+            }
+        }
+    };
+}
+
+impl EntityState for Sensor {
+    type State = Decimal;
+
+    fn state_payload(&self, state: &Decimal) -> Result<(&str, String), StateError> {
+        Ok((
+            non_empty(&self.state_topic, "state_topic")?,
+            state.to_string(),
+        ))
+    }
+
+    state_options!();
+}
+
+impl EntityState for BinarySensor {
+    type State = bool;
+
+    /// `payload_on` (default: `ON`) or `payload_off` (default: `OFF`).
+    fn state_payload(&self, state: &bool) -> Result<(&str, String), StateError> {
+        let payload = match state {
+            true => self.payload_on.as_deref().unwrap_or("ON"),
+            false => self.payload_off.as_deref().unwrap_or("OFF"),
+        };
+        Ok((
+            non_empty(&self.state_topic, "state_topic")?,
+            payload.to_string(),
+        ))
+    }
+
+    state_options!();
+}
+
+impl EntityState for Switch {
+    type State = bool;
+
+    /// `state_on` or `state_off`, defaulting to `payload_on` (default: `ON`) and `payload_off` (default: `OFF`).
+    fn state_payload(&self, state: &bool) -> Result<(&str, String), StateError> {
+        let payload = match state {
+            true => self
+                .state_on
+                .as_deref()
+                .or(self.payload_on.as_deref())
+                .unwrap_or("ON"),
+            false => self
+                .state_off
+                .as_deref()
+                .or(self.payload_off.as_deref())
+                .unwrap_or("OFF"),
+        };
+        Ok((
+            required(&self.state_topic, "state_topic")?,
+            payload.to_string(),
+        ))
+    }
+
+    state_options!(retain);
+}
+
+/// States of a cover.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoverState {
+    /// Published on `state_topic` as `state_open` (default: `open`).
+    Open,
+    /// Published on `state_topic` as `state_closed` (default: `closed`).
+    Closed,
+    /// Published on `state_topic` as `state_opening` (default: `opening`).
+    Opening,
+    /// Published on `state_topic` as `state_closing` (default: `closing`).
+    Closing,
+    /// Published on `state_topic` as `state_stopped` (default: `stopped`).
+    Stopped,
+    /// Position in percent, from closed (0) to open (100), published on `position_topic`
+    /// scaled between `position_closed` (default: 0) and `position_open` (default: 100).
+    Position(u8),
+    /// Tilt in percent, from closed (0) to open (100), published on `tilt_status_topic`
+    /// scaled between `tilt_min` (default: 0) and `tilt_max` (default: 100).
+    Tilt(u8),
+}
+
+/// Scales a percentage, capped to 100, between the closed and open values.
+fn scale_percentage(percentage: u8, closed: i32, open: i32) -> String {
+    let percentage = Decimal::from(percentage.min(100));
+    let value = Decimal::from(closed)
+        + Decimal::from(i64::from(open) - i64::from(closed)) * percentage / Decimal::ONE_HUNDRED;
+    value.round().to_string()
+}
+
+impl EntityState for Cover {
+    type State = CoverState;
+
+    fn state_payload(&self, state: &CoverState) -> Result<(&str, String), StateError> {
+        let state_payload = |payload: &Option<String>, default: &str| {
+            let topic = required(&self.state_topic, "state_topic")?;
+            Ok((topic, payload.as_deref().unwrap_or(default).to_string()))
+        };
+        match state {
+            CoverState::Open => state_payload(&self.state_open, "open"),
+            CoverState::Closed => state_payload(&self.state_closed, "closed"),
+            CoverState::Opening => state_payload(&self.state_opening, "opening"),
+            CoverState::Closing => state_payload(&self.state_closing, "closing"),
+            CoverState::Stopped => state_payload(&self.state_stopped, "stopped"),
+            CoverState::Position(position) => Ok((
+                required(&self.position_topic, "position_topic")?,
+                scale_percentage(
+                    *position,
+                    self.position_closed.unwrap_or(0),
+                    self.position_open.unwrap_or(100),
+                ),
+            )),
+            CoverState::Tilt(tilt) => Ok((
+                required(&self.tilt_status_topic, "tilt_status_topic")?,
+                scale_percentage(
+                    *tilt,
+                    self.tilt_min.unwrap_or(0),
+                    self.tilt_max.unwrap_or(100),
+                ),
+            )),
+        }
+    }
+
+    state_options!(retain);
+}
+
+/// States of a climate device, each published on its own topic.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClimateState {
+    /// Published on `current_temperature_topic`.
+    CurrentTemperature(Decimal),
+    /// Published on `temperature_state_topic`.
+    TargetTemperature(Decimal),
+    /// Published on `temperature_low_state_topic`.
+    TargetTemperatureLow(Decimal),
+    /// Published on `temperature_high_state_topic`.
+    TargetTemperatureHigh(Decimal),
+    /// Published on `current_humidity_topic`.
+    CurrentHumidity(Decimal),
+    /// Published on `target_humidity_state_topic`.
+    TargetHumidity(Decimal),
+    /// Published on `mode_state_topic`.
+    Mode(HvacMode),
+    /// Published on `action_topic`.
+    Action(ClimateAction),
+    /// Published on `fan_mode_state_topic`.
+    FanMode(FanMode),
+    /// Published on `swing_mode_state_topic`.
+    SwingMode(SwingMode),
+    /// Published on `preset_mode_state_topic`.
+    PresetMode(PresetMode),
+}
+
+impl EntityState for Climate {
+    type State = ClimateState;
+
+    fn state_payload(&self, state: &ClimateState) -> Result<(&str, String), StateError> {
+        let (topic, name, payload) = match state {
+            ClimateState::CurrentTemperature(temperature) => (
+                &self.current_temperature_topic,
+                "current_temperature_topic",
+                temperature.to_string(),
+            ),
+            ClimateState::TargetTemperature(temperature) => (
+                &self.temperature_state_topic,
+                "temperature_state_topic",
+                temperature.to_string(),
+            ),
+            ClimateState::TargetTemperatureLow(temperature) => (
+                &self.temperature_low_state_topic,
+                "temperature_low_state_topic",
+                temperature.to_string(),
+            ),
+            ClimateState::TargetTemperatureHigh(temperature) => (
+                &self.temperature_high_state_topic,
+                "temperature_high_state_topic",
+                temperature.to_string(),
+            ),
+            ClimateState::CurrentHumidity(humidity) => (
+                &self.current_humidity_topic,
+                "current_humidity_topic",
+                humidity.to_string(),
+            ),
+            ClimateState::TargetHumidity(humidity) => (
+                &self.target_humidity_state_topic,
+                "target_humidity_state_topic",
+                humidity.to_string(),
+            ),
+            ClimateState::Mode(mode) => {
+                (&self.mode_state_topic, "mode_state_topic", mode.to_string())
+            }
+            ClimateState::Action(action) => {
+                (&self.action_topic, "action_topic", action.to_string())
+            }
+            ClimateState::FanMode(mode) => (
+                &self.fan_mode_state_topic,
+                "fan_mode_state_topic",
+                mode.to_string(),
+            ),
+            ClimateState::SwingMode(mode) => (
+                &self.swing_mode_state_topic,
+                "swing_mode_state_topic",
+                mode.to_string(),
+            ),
+            ClimateState::PresetMode(mode) => (
+                &self.preset_mode_state_topic,
+                "preset_mode_state_topic",
+                mode.to_string(),
+            ),
+        };
+        Ok((required(topic, name)?, payload))
+    }
+
+    state_options!(retain);
+}
+
+/// States of a lock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockState {
+    /// Published as `state_locked` (default: `LOCKED`).
+    Locked,
+    /// Published as `state_locking` (default: `LOCKING`).
+    Locking,
+    /// Published as `state_unlocked` (default: `UNLOCKED`).
+    Unlocked,
+    /// Published as `state_unlocking` (default: `UNLOCKING`).
+    Unlocking,
+    /// Published as `state_jammed` (default: `JAMMED`).
+    Jammed,
+}
+
+impl EntityState for Lock {
+    type State = LockState;
+
+    fn state_payload(&self, state: &LockState) -> Result<(&str, String), StateError> {
+        let (payload, default) = match state {
+            LockState::Locked => (&self.state_locked, "LOCKED"),
+            LockState::Locking => (&self.state_locking, "LOCKING"),
+            LockState::Unlocked => (&self.state_unlocked, "UNLOCKED"),
+            LockState::Unlocking => (&self.state_unlocking, "UNLOCKING"),
+            LockState::Jammed => (&self.state_jammed, "JAMMED"),
+        };
+        Ok((
+            required(&self.state_topic, "state_topic")?,
+            payload.as_deref().unwrap_or(default).to_string(),
+        ))
+    }
+
+    state_options!(retain);
+}
+
+impl EntityState for Number {
+    type State = Decimal;
+
+    fn state_payload(&self, state: &Decimal) -> Result<(&str, String), StateError> {
+        Ok((
+            required(&self.state_topic, "state_topic")?,
+            state.to_string(),
+        ))
+    }
+
+    state_options!(retain);
+}
+
+impl EntityState for Select {
+    /// One of the `options` of the select.
+    type State = String;
+
+    fn state_payload(&self, state: &String) -> Result<(&str, String), StateError> {
+        Ok((required(&self.state_topic, "state_topic")?, state.clone()))
+    }
+
+    state_options!(retain);
+}
+
+impl EntityState for Text {
+    type State = String;
+
+    fn state_payload(&self, state: &String) -> Result<(&str, String), StateError> {
+        Ok((required(&self.state_topic, "state_topic")?, state.clone()))
+    }
+
+    state_options!(retain);
+}
+
+string_enum! {
+    /// Activities of a vacuum, published in the `state` of its [`VacuumState`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum VacuumActivity {
+        #[serde(rename = "cleaning")]
+        Cleaning,
+        #[serde(rename = "docked")]
+        Docked,
+        #[serde(rename = "paused")]
+        Paused,
+        #[serde(rename = "idle")]
+        Idle,
+        #[serde(rename = "returning")]
+        Returning,
+        #[serde(rename = "error")]
+        Error,
+    }
+}
+
+/// State of a vacuum, published as a JSON object on `state_topic`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VacuumState {
+    pub state: VacuumActivity,
+    /// The battery level in percent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery_level: Option<u8>,
+    /// One of the `fan_speed_list` of the vacuum.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fan_speed: Option<String>,
+    /// Any other attribute of the state.
+    #[serde(flatten)]
+    pub attributes: Map<String, Value>,
+}
+
+impl VacuumState {
+    pub fn new(state: VacuumActivity) -> Self {
+        Self {
+            state,
+            battery_level: None,
+            fan_speed: None,
+            attributes: Map::new(),
+        }
+    }
+
+    pub fn battery_level(mut self, battery_level: u8) -> Self {
+        self.battery_level = Some(battery_level);
+        self
+    }
+
+    pub fn fan_speed<S: Into<String>>(mut self, fan_speed: S) -> Self {
+        self.fan_speed = Some(fan_speed.into());
+        self
+    }
+
+    pub fn add_attribute<K: Into<String>, V: Into<Value>>(mut self, name: K, value: V) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+}
+
+impl EntityState for Vacuum {
+    type State = VacuumState;
+
+    fn state_payload(&self, state: &VacuumState) -> Result<(&str, String), StateError> {
+        if let (Some(speed), Some(speeds)) = (&state.fan_speed, &self.fan_speed_list) {
+            if !speeds.contains(speed) {
+                return Err(StateError::InvalidState(format!(
+                    "fan speed '{speed}' should be one of the fan_speed_list"
+                )));
+            }
+        }
+        let payload = serde_json::to_string(state)
+            .map_err(|error| StateError::InvalidState(error.to_string()))?;
+        Ok((required(&self.state_topic, "state_topic")?, payload))
+    }
+
+    state_options!(retain);
+}
+
+string_enum! {
+    /// Activities of a lawn mower, published on `activity_state_topic`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum LawnMowerActivity {
+        #[serde(rename = "mowing")]
+        Mowing,
+        #[serde(rename = "docked")]
+        Docked,
+        #[serde(rename = "paused")]
+        Paused,
+        #[serde(rename = "returning")]
+        Returning,
+        #[serde(rename = "error")]
+        Error,
+    }
+}
+
+impl EntityState for LawnMower {
+    type State = LawnMowerActivity;
+
+    fn state_payload(&self, state: &LawnMowerActivity) -> Result<(&str, String), StateError> {
+        Ok((
+            required(&self.activity_state_topic, "activity_state_topic")?,
+            state.to_string(),
+        ))
+    }
+
+    state_options!(retain);
+}
+
+#[cfg(feature = "alarm")]
+impl EntityState for AlarmControlPanel {
+    type State = AlarmState;
+
+    fn state_payload(&self, state: &AlarmState) -> Result<(&str, String), StateError> {
+        Ok((
+            non_empty(&self.state_topic, "state_topic")?,
+            state.to_string(),
+        ))
+    }
+
+    state_options!(retain);
+}
+
+/// Publishes the states of entities through any [`DiscoveryTransport`],
+/// on the topics and with the QoS level and retain flag of their configuration.
+#[derive(Clone)]
+pub struct StatePublisher<T> {
+    client: T,
+}
+
+impl<T: DiscoveryTransport> StatePublisher<T> {
+    pub fn new(client: T) -> Self {
+        Self { client }
+    }
+
+    /// The MQTT client used to publish messages.
+    pub fn client(&self) -> &T {
+        &self.client
+    }
+
+    /// Publishes the state of the entity on its state topic, with `~` resolved.
+    pub async fn publish_state<E: EntityState>(
+        &self,
+        entity: &E,
+        state: &E::State,
+    ) -> Result<(), PublishError<T::Error>> {
+        let (topic, payload) = entity.state_payload(state)?;
+        self.publish(entity, topic, payload.into_bytes()).await
+    }
+
+    /// Publishes the JSON attributes of the entity on its `json_attributes_topic`, with `~` resolved.
+    pub async fn publish_attributes<E: EntityState, S: serde::Serialize>(
+        &self,
+        entity: &E,
+        attributes: &S,
+    ) -> Result<(), PublishError<T::Error>> {
+        let topic = entity
+            .state_options()
+            .json_attributes_topic
+            .ok_or(StateError::MissingTopic("json_attributes_topic"))?;
+        self.publish(entity, topic, serde_json::to_vec(attributes)?)
+            .await
+    }
+
+    async fn publish<E: EntityState>(
+        &self,
+        entity: &E,
+        topic: &str,
+        payload: Vec<u8>,
+    ) -> Result<(), PublishError<T::Error>> {
+        let options = entity.state_options();
+        let message = Message::new(
+            resolve_topic(options.topic_prefix, topic),
+            payload,
+            options.retain,
+        )
+        .qos(options.qos.unwrap_or(Qos::AtMostOnce));
+        self.client
+            .publish(message)
+            .await
+            .map_err(PublishError::Transport)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::command::{CoverCommand, EntityCommands};
+    use crate::transport::testing::{block_on, RecordingTransport};
+
+    #[test]
+    fn can_publish_a_sensor_state_on_its_resolved_topic() {
+        let transport = RecordingTransport::default();
+        let publisher = StatePublisher::new(transport.clone());
+        let sensor = Sensor::default()
+            .topic_prefix("thermometers/01")
+            .state_topic("~/state")
+            .json_attributes_topic("~/attributes")
+            .qos(Qos::AtLeastOnce);
+        block_on(async {
+            publisher.publish_state(&sensor, &dec!(21.5)).await?;
+            publisher
+                .publish_attributes(&sensor, &serde_json::json!({"battery": 80}))
+                .await
+        })
+        .unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        assert_eq!(
+            messages[0],
+            Message::new("thermometers/01/state", "21.5", false).qos(Qos::AtLeastOnce)
+        );
+        assert_eq!(messages[1].topic, "thermometers/01/attributes");
+        assert_eq!(messages[1].payload, br#"{"battery":80}"#);
+    }
+
+    #[test]
+    fn can_publish_states_through_the_configured_payloads() {
+        let transport = RecordingTransport::default();
+        let publisher = StatePublisher::new(transport.clone());
+        let door = BinarySensor::default()
+            .state_topic("door/state")
+            .payload_on("opened");
+        let cover = Cover::default()
+            .state_topic("garage/state")
+            .position_topic("garage/position")
+            .position_open(255)
+            .retain(true);
+        block_on(async {
+            publisher.publish_state(&door, &true).await?;
+            publisher.publish_state(&door, &false).await?;
+            publisher
+                .publish_state(&cover, &CoverState::Opening)
+                .await?;
+            publisher
+                .publish_state(&cover, &CoverState::Position(50))
+                .await
+        })
+        .unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        let payloads: Vec<(&str, &[u8], bool)> = messages
+            .iter()
+            .map(|message| {
+                (
+                    message.topic.as_str(),
+                    message.payload.as_slice(),
+                    message.retain,
+                )
+            })
+            .collect();
+        assert_eq!(
+            payloads,
+            vec![
+                ("door/state", b"opened".as_slice(), false),
+                ("door/state", b"OFF".as_slice(), false),
+                ("garage/state", b"opening".as_slice(), true),
+                ("garage/position", b"128".as_slice(), true),
+            ]
+        );
+        assert_eq!(messages[0].qos, Qos::AtMostOnce);
+    }
+
+    #[test]
+    fn can_publish_a_vacuum_state_as_json() {
+        let transport = RecordingTransport::default();
+        let publisher = StatePublisher::new(transport.clone());
+        let vacuum = Vacuum::default()
+            .state_topic("vacuum/state")
+            .fan_speed_list(vec!["min".to_string(), "max".to_string()]);
+        let state = VacuumState::new(VacuumActivity::Cleaning)
+            .battery_level(61)
+            .fan_speed("max")
+            .add_attribute("area", "kitchen");
+        block_on(publisher.publish_state(&vacuum, &state)).unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&messages[0].payload).unwrap(),
+            serde_json::json!({
+                "state": "cleaning",
+                "battery_level": 61,
+                "fan_speed": "max",
+                "area": "kitchen"
+            })
+        );
+        assert!(matches!(
+            vacuum.state_payload(&state.fan_speed("turbo")),
+            Err(StateError::InvalidState(_))
+        ));
+    }
+
+    #[test]
+    fn can_publish_a_tilt_within_the_range_of_the_cover() {
+        let transport = RecordingTransport::default();
+        let publisher = StatePublisher::new(transport.clone());
+        let blind = Cover::default()
+            .tilt_command_topic("blind/tilt/set")
+            .tilt_status_topic("blind/tilt")
+            .tilt_min(180)
+            .tilt_max(0)
+            .tilt_closed_value(70)
+            .tilt_opened_value(10);
+        let tilt = match blind.decode_command("blind/tilt/set", b"45") {
+            Some(Ok(CoverCommand::SetTilt(tilt))) => tilt,
+            command => panic!("unexpected command {command:?}"),
+        };
+        assert_eq!(tilt, 75);
+        block_on(publisher.publish_state(&blind, &CoverState::Tilt(tilt))).unwrap();
+
+        let messages = transport.messages.lock().unwrap();
+        assert_eq!(messages[0].topic, "blind/tilt");
+        assert_eq!(messages[0].payload, b"45");
+    }
+
+    #[test]
+    fn cannot_publish_a_state_without_its_topic() {
+        let publisher = StatePublisher::new(RecordingTransport::default());
+        let result = block_on(publisher.publish_state(
+            &Climate::default().mode_state_topic("hvac/mode"),
+            &ClimateState::CurrentTemperature(dec!(19)),
+        ));
+        assert!(matches!(
+            result,
+            Err(PublishError::MissingTopic("current_temperature_topic"))
+        ));
+    }
+
+    #[test]
+    fn cannot_publish_a_state_on_an_empty_topic() {
+        let publisher = StatePublisher::new(RecordingTransport::default());
+        let result = block_on(publisher.publish_state(&Sensor::default(), &dec!(21.5)));
+        assert!(matches!(
+            result,
+            Err(PublishError::MissingTopic("state_topic"))
+        ));
+        let result = block_on(publisher.publish_state(&BinarySensor::default(), &true));
+        assert!(matches!(
+            result,
+            Err(PublishError::MissingTopic("state_topic"))
+        ));
+    }
+}
@@ -0,0 +1,152 @@
+use serde_json::{Map, Value};
+
+use crate::mqtt::abbreviations::{ABBREVIATIONS, DEVICE_ABBREVIATIONS, ORIGIN_ABBREVIATIONS};
+
+/// Naming of the attributes in the serialized discovery payloads.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Style {
+    /// Abbreviated names such as `stat_t`, keeping the retained payloads small.
+    #[default]
+    Abbreviated,
+    /// Full names such as `state_topic`, easier to read when debugging.
+    Full,
+}
+
+impl Style {
+    /// Renames the attributes of a serialized configuration, abbreviated by default, according to the style.
+    pub(crate) fn apply(self, attributes: Value) -> Value {
+        match (self, attributes) {
+            (Style::Full, Value::Object(attributes)) => Value::Object(expand_names(attributes)),
+            (_, attributes) => attributes,
+        }
+    }
+}
+
+/// The full name of an attribute, or the name itself when it isn't abbreviated.
+pub fn full_name<'a>(abbreviations: &[(&str, &'a str)], name: &'a str) -> &'a str {
+    abbreviations
+        .iter()
+        .find(|(abbreviation, _)| *abbreviation == name)
+        .map_or(name, |(_, full_name)| full_name)
+}
+
+fn rename(attributes: Map<String, Value>, abbreviations: &[(&str, &str)]) -> Map<String, Value> {
+    attributes
+        .into_iter()
+        .map(|(name, value)| (full_name(abbreviations, &name).to_string(), value))
+        .collect()
+}
+
+/// Replaces the abbreviated names of a configuration by their full names,
+/// including the attributes of the device, the origin, the availability list and the components.
+fn expand_names(attributes: Map<String, Value>) -> Map<String, Value> {
+    rename(attributes, ABBREVIATIONS)
+        .into_iter()
+        .map(|(name, value)| {
+            let value = match (name.as_str(), value) {
+                ("device", Value::Object(device)) => {
+                    Value::Object(rename(device, DEVICE_ABBREVIATIONS))
+                }
+                ("origin", Value::Object(origin)) => {
+                    Value::Object(rename(origin, ORIGIN_ABBREVIATIONS))
+                }
+                ("availability", Value::Array(checks)) => Value::Array(
+                    checks
+                        .into_iter()
+                        .map(|check| match check {
+                            Value::Object(check) => Value::Object(rename(check, ABBREVIATIONS)),
+                            check => check,
+                        })
+                        .collect(),
+                ),
+                ("components", Value::Object(components)) => Value::Object(
+                    components
+                        .into_iter()
+                        .map(|(id, component)| (id, Style::Full.apply(component)))
+                        .collect(),
+                ),
+                (_, value) => value,
+            };
+            (name, value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::mqtt::common::{Availability, AvailabilityCheck, Device, DeviceConnection, Origin};
+    use crate::mqtt::sensor::Sensor;
+    use crate::mqtt::units::{TempUnit, Unit};
+    use crate::{DeviceDiscovery, Entity};
+
+    #[test]
+    fn can_serialize_an_entity_with_full_names() {
+        let entity: Entity = Sensor::default()
+            .origin(Origin::new("Integration test").with_sw_version("0.0.1"))
+            .device(
+                Device::default()
+                    .add_identifier("thermometer-01")
+                    .add_connection(DeviceConnection::mac("09:AF:A4:54:F0:9D")),
+            )
+            .availability(Availability::single(AvailabilityCheck::topic(
+                "~/availability",
+            )))
+            .topic_prefix("thermometers/01")
+            .state_topic("~/state")
+            .unique_id("thermometer-01_temperature")
+            .unit_of_measurement(Unit::Temperature(TempUnit::Celsius))
+            .into();
+
+        assert_json_eq!(
+            entity.get_attributes_with(Style::Full).unwrap(),
+            json!({
+                "~": "thermometers/01",
+                "origin": {"name": "Integration test", "sw_version": "0.0.1"},
+                "device": {
+                    "identifiers": ["thermometer-01"],
+                    "connections": [["mac", "09:AF:A4:54:F0:9D"]]
+                },
+                "availability_mode": "all",
+                "availability": [{"topic": "~/availability"}],
+                "platform": "sensor",
+                "state_topic": "~/state",
+                "unique_id": "thermometer-01_temperature",
+                "unit_of_measurement": "°C"
+            })
+        );
+        assert_eq!(
+            entity.get_attributes_with(Style::Abbreviated).unwrap(),
+            entity.get_attributes().unwrap()
+        );
+    }
+
+    #[test]
+    fn can_serialize_the_components_of_a_device_with_full_names() {
+        let discovery =
+            DeviceDiscovery::new("thermometer-01", Device::default(), Origin::default())
+                .state_topic("thermometers/01/state")
+                .add_component(Sensor::default().unique_id("thermometer-01_temperature"));
+
+        assert_json_eq!(
+            Style::Full.apply(serde_json::to_value(&discovery).unwrap()),
+            json!({
+                "device": {},
+                "origin": {"name": ""},
+                "state_topic": "thermometers/01/state",
+                "components": {
+                    "thermometer-01_temperature": {
+                        "platform": "sensor",
+                        "availability_mode": "all",
+                        "availability": [],
+                        "state_topic": "",
+                        "unique_id": "thermometer-01_temperature"
+                    }
+                }
+            })
+        );
+    }
+}
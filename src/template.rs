@@ -0,0 +1,577 @@
+//! Local rendering of the [templates](https://www.home-assistant.io/docs/configuration/templating/)
+//! of entity configurations, such as `value_template` or `command_template`,
+//! with the variables Home Assistant provides to them.
+
+use std::collections::BTreeMap;
+
+use minijinja::value::Value;
+use minijinja::{Environment, Error, ErrorKind, UndefinedBehavior};
+use serde::Serialize;
+
+use crate::mqtt::cover::Cover;
+use crate::validation::ValidationError;
+use crate::{Entity, Style};
+
+/// Filters of Jinja and the ones added by Home Assistant.
+const KNOWN_FILTERS: &[&str] = &[
+    // Jinja
+    "abs",
+    "attr",
+    "batch",
+    "capitalize",
+    "center",
+    "count",
+    "d",
+    "default",
+    "dictsort",
+    "e",
+    "escape",
+    "filesizeformat",
+    "first",
+    "float",
+    "forceescape",
+    "format",
+    "groupby",
+    "indent",
+    "int",
+    "items",
+    "join",
+    "last",
+    "length",
+    "list",
+    "lower",
+    "map",
+    "max",
+    "min",
+    "pprint",
+    "random",
+    "reject",
+    "rejectattr",
+    "replace",
+    "reverse",
+    "round",
+    "safe",
+    "select",
+    "selectattr",
+    "slice",
+    "sort",
+    "string",
+    "striptags",
+    "sum",
+    "title",
+    "tojson",
+    "trim",
+    "truncate",
+    "unique",
+    "upper",
+    "urlencode",
+    "urlize",
+    "wordcount",
+    "wordwrap",
+    "xmlattr",
+    // Home Assistant
+    "acos",
+    "add",
+    "apply",
+    "area_devices",
+    "area_entities",
+    "area_id",
+    "area_name",
+    "as_datetime",
+    "as_local",
+    "as_timedelta",
+    "as_timestamp",
+    "asin",
+    "atan",
+    "atan2",
+    "average",
+    "base64_decode",
+    "base64_encode",
+    "bitwise_and",
+    "bitwise_or",
+    "bitwise_xor",
+    "bool",
+    "closest",
+    "combine",
+    "config_entry_attr",
+    "config_entry_id",
+    "contains",
+    "cos",
+    "device_attr",
+    "device_entities",
+    "device_id",
+    "device_name",
+    "difference",
+    "expand",
+    "flatten",
+    "floor_areas",
+    "floor_entities",
+    "floor_id",
+    "floor_name",
+    "from_hex",
+    "from_json",
+    "has_value",
+    "iif",
+    "integration_entities",
+    "intersect",
+    "is_defined",
+    "is_device_attr",
+    "is_hidden_entity",
+    "is_number",
+    "is_state",
+    "is_state_attr",
+    "label_areas",
+    "label_devices",
+    "label_entities",
+    "label_id",
+    "label_name",
+    "log",
+    "md5",
+    "median",
+    "merge_response",
+    "multiply",
+    "ord",
+    "ordinal",
+    "pack",
+    "regex_findall",
+    "regex_findall_index",
+    "regex_match",
+    "regex_replace",
+    "regex_search",
+    "relative_time",
+    "sha1",
+    "sha256",
+    "sha512",
+    "shuffle",
+    "sin",
+    "slugify",
+    "sqrt",
+    "state_attr",
+    "state_translated",
+    "states",
+    "statistical_mode",
+    "symmetric_difference",
+    "tan",
+    "time_since",
+    "time_until",
+    "timestamp_custom",
+    "timestamp_local",
+    "timestamp_utc",
+    "to_json",
+    "today_at",
+    "typeof",
+    "union",
+    "unpack",
+    "version",
+];
+
+/// The variables available to a template.
+#[derive(Clone, Debug, Default)]
+pub struct TemplateVariables {
+    variables: BTreeMap<String, Value>,
+}
+
+impl TemplateVariables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The received payload as `value`, and as `value_json` when it is valid JSON.
+    pub fn value<S: Into<String>>(mut self, payload: S) -> Self {
+        let payload = payload.into();
+        match serde_json::from_str::<serde_json::Value>(&payload) {
+            Ok(json) => self.insert("value_json", Value::from_serialize(json)),
+            Err(_) => self.variables.remove("value_json"),
+        };
+        self.insert("value", Value::from(payload));
+        self
+    }
+
+    /// The ID of the entity in Home Assistant, such as `cover.garage_door`.
+    pub fn entity_id<S: Into<String>>(mut self, entity_id: S) -> Self {
+        self.insert("entity_id", Value::from(entity_id.into()));
+        self
+    }
+
+    /// Any other variable, such as the `position` given to `set_position_template`.
+    pub fn variable<S: Into<String>, V: Serialize>(mut self, name: S, value: V) -> Self {
+        self.insert(name.into(), Value::from_serialize(value));
+        self
+    }
+
+    fn insert<S: Into<String>>(&mut self, name: S, value: Value) -> Option<Value> {
+        self.variables.insert(name.into(), value)
+    }
+}
+
+impl Cover {
+    /// The variables Home Assistant gives to the position and tilt templates of the cover:
+    /// `position_open`, `position_closed`, `tilt_min` and `tilt_max`, with their default values.
+    pub fn template_variables(&self) -> TemplateVariables {
+        TemplateVariables::new()
+            .variable("position_open", self.position_open.unwrap_or(100))
+            .variable("position_closed", self.position_closed.unwrap_or(0))
+            .variable("tilt_min", self.tilt_min.unwrap_or(0))
+            .variable("tilt_max", self.tilt_max.unwrap_or(100))
+    }
+}
+
+/// Renders templates with the filters Home Assistant adds to Jinja:
+/// `float` and `int` with a default value, `is_defined`, `from_json`, `to_json`, `iif` and `multiply`.
+pub struct TemplateRenderer {
+    environment: Environment<'static>,
+}
+
+impl Default for TemplateRenderer {
+    fn default() -> Self {
+        let mut environment = Environment::new();
+        // like Home Assistant, attributes of undefined values such as `value_json` are undefined as well
+        environment.set_undefined_behavior(UndefinedBehavior::Chainable);
+        environment.add_filter("float", float);
+        environment.add_filter("int", int);
+        environment.add_filter("is_defined", is_defined);
+        environment.add_filter("from_json", from_json);
+        environment.add_filter("to_json", to_json);
+        environment.add_filter("iif", iif);
+        environment.add_filter("multiply", multiply);
+        environment.add_function("float", float);
+        environment.add_function("int", int);
+        environment.add_function("iif", iif);
+        Self { environment }
+    }
+}
+
+impl TemplateRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders the template with the variables.
+    pub fn render(&self, template: &str, variables: &TemplateVariables) -> Result<String, Error> {
+        self.environment.render_str(template, &variables.variables)
+    }
+
+    /// Renders a `value_template` the way Home Assistant extracts a state from a received payload.
+    pub fn render_value(&self, template: &str, payload: &str) -> Result<String, Error> {
+        self.render(template, &TemplateVariables::new().value(payload))
+    }
+}
+
+/// The names of the filters applied in the expressions and statements of a template, with their line.
+/// The template is expected to be syntactically valid.
+fn filters(template: &str) -> Vec<(&str, usize)> {
+    let mut filters = Vec::new();
+    let mut chars = template.char_indices().peekable();
+    // the delimiter closing the current block, if any, and the quote of the current string
+    let mut close = None;
+    let mut quote = None;
+    while let Some((position, c)) = chars.next() {
+        let rest = &template[position..];
+        match (close, quote) {
+            (None, _) => {
+                close = match rest.get(..2) {
+                    Some("{{") => Some("}}"),
+                    Some("{%") => Some("%}"),
+                    Some("{#") => Some("#}"),
+                    _ => None,
+                };
+                if close.is_some() {
+                    chars.next();
+                }
+            }
+            (Some(_), Some(_)) if c == '\\' => {
+                chars.next();
+            }
+            (Some(_), Some(q)) => {
+                if c == q {
+                    quote = None;
+                }
+            }
+            (Some(delimiter), None) if rest.starts_with(delimiter) => {
+                close = None;
+                chars.next();
+            }
+            (Some("#}"), None) => {}
+            (Some(_), None) if c == '\'' || c == '"' => quote = Some(c),
+            (Some(_), None) if c == '|' => {
+                let name = rest[1..].trim_start();
+                let length = name
+                    .find(|c: char| !c.is_alphanumeric() && c != '_')
+                    .unwrap_or(name.len());
+                if length > 0 {
+                    let line = template[..position].matches('\n').count() + 1;
+                    filters.push((&name[..length], line));
+                }
+            }
+            (Some(_), None) => {}
+        }
+    }
+    filters
+}
+
+/// Parses a template, returning the location and description of its first error.
+fn check_template(environment: &Environment, template: &str) -> Result<(), String> {
+    if let Err(error) = environment.template_from_str(template) {
+        let detail = error
+            .detail()
+            .map_or(error.kind().to_string(), str::to_string);
+        return Err(match error.line() {
+            Some(line) => format!("syntax error at line {line}: {detail}"),
+            None => format!("syntax error: {detail}"),
+        });
+    }
+    match filters(template)
+        .into_iter()
+        .find(|(filter, _)| !KNOWN_FILTERS.contains(filter))
+    {
+        Some((filter, line)) => Err(format!(
+            "unknown filter '{filter}' at line {line}, not provided by Home Assistant"
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Checks the templates of the attributes, and of the objects they hold such as the availability list.
+fn check_attributes(
+    environment: &Environment,
+    path: &str,
+    value: &serde_json::Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    match value {
+        serde_json::Value::Object(attributes) => {
+            for (name, value) in attributes {
+                let path = match path {
+                    "" => name.clone(),
+                    _ => format!("{path}.{name}"),
+                };
+                match value.as_str() {
+                    Some(template) if name.ends_with("_template") => {
+                        if let Err(message) = check_template(environment, template) {
+                            errors.push(ValidationError { path, message });
+                        }
+                    }
+                    _ => check_attributes(environment, &path, value, errors),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                check_attributes(environment, &format!("{path}[{index}]"), value, errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl Entity {
+    /// Parses every template of the entity, such as `value_template`, `command_template`
+    /// or the `value_template` of its availability topics,
+    /// reporting syntax errors and filters Home Assistant doesn't provide.
+    /// A configuration which can't be serialized is reported on the name of its component.
+    pub fn check_templates(&self) -> Result<(), Vec<ValidationError>> {
+        let attributes = self.get_attributes_with(Style::Full).map_err(|error| {
+            vec![ValidationError {
+                path: self.get_component_name().to_string(),
+                message: format!("configuration can't be serialized: {error}"),
+            }]
+        })?;
+        let mut errors = Vec::new();
+        check_attributes(&Environment::new(), "", &attributes, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn invalid(message: String) -> Error {
+    Error::new(ErrorKind::InvalidOperation, message)
+}
+
+/// The integer or decimal number written in the text.
+fn parse_number(text: &str) -> Option<Value> {
+    let text = text.trim();
+    text.parse::<i64>()
+        .map(Value::from)
+        .or_else(|_| text.parse::<f64>().map(Value::from))
+        .ok()
+}
+
+/// The number, or the default value when it can't be converted, failing without default like Home Assistant.
+/// Strings are converted as the number they contain.
+fn number<T: Into<Value>>(
+    value: &Value,
+    default: Option<Value>,
+    convert: impl Fn(&Value) -> Option<T>,
+) -> Result<Value, Error> {
+    let number = match value.as_str() {
+        Some(text) => parse_number(text).and_then(|number| convert(&number)),
+        None => convert(value),
+    };
+    match (number, default) {
+        (Some(number), _) => Ok(number.into()),
+        (None, Some(default)) => Ok(default),
+        (None, None) => Err(invalid(format!(
+            "{value} cannot be converted to a number and no default was specified"
+        ))),
+    }
+}
+
+fn float(value: Value, default: Option<Value>) -> Result<Value, Error> {
+    number::<f64>(&value, default, |value| f64::try_from(value.clone()).ok())
+}
+
+fn int(value: Value, default: Option<Value>) -> Result<Value, Error> {
+    number::<i64>(&value, default, |value| {
+        i64::try_from(value.clone()).ok().or_else(|| {
+            f64::try_from(value.clone())
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(|value| value.trunc() as i64)
+        })
+    })
+}
+
+fn is_defined(value: Value) -> Result<Value, Error> {
+    if value.is_undefined() {
+        return Err(Error::new(ErrorKind::UndefinedError, "value is undefined"));
+    }
+    Ok(value)
+}
+
+fn from_json(value: String) -> Result<Value, Error> {
+    serde_json::from_str::<serde_json::Value>(&value)
+        .map(Value::from_serialize)
+        .map_err(|error| invalid(format!("invalid JSON: {error}")))
+}
+
+fn to_json(value: Value) -> Result<String, Error> {
+    serde_json::to_string(&value).map_err(|error| invalid(error.to_string()))
+}
+
+fn iif(
+    value: Value,
+    if_true: Option<Value>,
+    if_false: Option<Value>,
+    if_none: Option<Value>,
+) -> Value {
+    if value.is_none() || value.is_undefined() {
+        if let Some(if_none) = if_none {
+            return if_none;
+        }
+    }
+    match value.is_true() {
+        true => if_true.unwrap_or(Value::from(true)),
+        false => if_false.unwrap_or(Value::from(false)),
+    }
+}
+
+fn multiply(value: Value, amount: f64, default: Option<Value>) -> Result<Value, Error> {
+    let number = float(value, default.clone())?;
+    match f64::try_from(number) {
+        Ok(number) => Ok(Value::from(number * amount)),
+        Err(_) => default.ok_or_else(|| invalid("value cannot be multiplied".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mqtt::common::{Availability, AvailabilityCheck};
+
+    #[test]
+    fn can_render_a_value_template_from_a_json_payload() {
+        let renderer = TemplateRenderer::new();
+        assert_eq!(
+            renderer
+                .render_value(
+                    "{{ value_json.temperature | float(0) | round(1) }}",
+                    r#"{"temperature": "21.46"}"#
+                )
+                .unwrap(),
+            "21.5"
+        );
+        assert_eq!(
+            renderer
+                .render_value("{{ value_json.temperature | float(0) }}", "offline")
+                .unwrap(),
+            "0"
+        );
+        assert_eq!(
+            renderer
+                .render_value("{{ 'ON' if value == 'open' else 'OFF' }}", "open")
+                .unwrap(),
+            "ON"
+        );
+        assert!(renderer
+            .render_value("{{ value_json.humidity | is_defined }}", "{}")
+            .is_err());
+        assert_eq!(
+            renderer.render_value("{{ value | int }}", "21.5").unwrap(),
+            "21"
+        );
+        assert_eq!(
+            renderer
+                .render_value("{{ value | int(-1) }}", "nan")
+                .unwrap(),
+            "-1"
+        );
+    }
+
+    #[test]
+    fn can_render_a_cover_template_with_its_variables() {
+        let cover = Cover::default().position_open(255);
+        let variables = cover
+            .template_variables()
+            .entity_id("cover.garage_door")
+            .variable("position", 50);
+        assert_eq!(
+            TemplateRenderer::new()
+                .render(
+                    "{{ entity_id }}={{ (position * (position_open - position_closed) / 100) | int }}",
+                    &variables
+                )
+                .unwrap(),
+            "cover.garage_door=127"
+        );
+    }
+
+    #[test]
+    fn can_check_the_templates_of_an_entity() {
+        let valid: Entity = crate::mqtt::sensor::Sensor::default()
+            .value_template("{{ value_json['a|b'] | from_json | float(0) | round(1) }}")
+            .into();
+        assert_eq!(valid.check_templates(), Ok(()));
+        let valid: Entity = crate::mqtt::sensor::Sensor::default()
+            .value_template(
+                "{{ 'sensor.power' | has_value and 'kitchen' | area_entities | count }}",
+            )
+            .json_attributes_template("{{ {'state': 'sensor.mode' | state_translated} | to_json }}")
+            .into();
+        assert_eq!(valid.check_templates(), Ok(()));
+
+        let invalid: Entity = crate::mqtt::sensor::Sensor::default()
+            .value_template("{{ value_json.temperature | float(0) }")
+            .json_attributes_template("{# a | comment #}\n{{ value_json | hex }}")
+            .into();
+        let errors = invalid.check_templates().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path, "json_attributes_template");
+        assert_eq!(
+            errors[0].message,
+            "unknown filter 'hex' at line 2, not provided by Home Assistant"
+        );
+        assert_eq!(errors[1].path, "value_template");
+        assert!(errors[1].message.starts_with("syntax error at line 1"));
+
+        let availability: Entity = crate::mqtt::sensor::Sensor::default()
+            .availability(Availability::single(
+                AvailabilityCheck::topic("~/online").value_template("{{ value | hex }"),
+            ))
+            .into();
+        let errors = availability.check_templates().unwrap_err();
+        assert_eq!(errors[0].path, "availability[0].value_template");
+    }
+}
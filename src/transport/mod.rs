@@ -0,0 +1,76 @@
+use std::future::Future;
+
+use crate::mqtt::common::Qos;
+
+/// A message to be published on the MQTT broker.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    /// The topic the message is published to.
+    pub topic: String,
+    /// The raw payload of the message.
+    pub payload: Vec<u8>,
+    /// The QoS level the message is published with.
+    pub qos: Qos,
+    /// Whether the broker should retain the message.
+    pub retain: bool,
+    /// MIME type of the payload. Only used by MQTT v5 transports.
+    pub content_type: Option<String>,
+    /// Number of seconds after which the broker drops the message. Only used by MQTT v5 transports.
+    pub message_expiry_interval: Option<u32>,
+}
+
+impl Message {
+    /// A message published with QoS 1 and without any MQTT v5 property.
+    pub fn new<S: Into<String>, P: Into<Vec<u8>>>(topic: S, payload: P, retain: bool) -> Self {
+        Message {
+            topic: topic.into(),
+            payload: payload.into(),
+            qos: Qos::AtLeastOnce,
+            retain,
+            content_type: None,
+            message_expiry_interval: None,
+        }
+    }
+
+    /// The QoS level the message is published with.
+    pub fn qos(mut self, qos: Qos) -> Self {
+        self.qos = qos;
+        self
+    }
+
+    /// MIME type of the payload. Only used by MQTT v5 transports.
+    pub fn content_type<S: Into<String>>(mut self, content_type: S) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Number of seconds after which the broker drops the message. Only used by MQTT v5 transports.
+    pub fn message_expiry_interval(mut self, message_expiry_interval: Option<u32>) -> Self {
+        self.message_expiry_interval = message_expiry_interval;
+        self
+    }
+}
+
+/// The MQTT client used to send discovery messages to Home Assistant.
+///
+/// Implement this trait to plug any MQTT client into [`crate::HomeAssistantMqtt`].
+pub trait DiscoveryTransport {
+    /// The error returned by the underlying MQTT client.
+    type Error;
+
+    /// Publishes a single message on the broker.
+    fn publish(&self, message: Message) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Subscribes to a topic. Received messages must be handed back to the publisher by the caller,
+    /// see [`crate::HomeAssistantMqtt::handle_message`].
+    fn subscribe(
+        &self,
+        topic: String,
+        qos: Qos,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+#[cfg(feature = "rumqttc")]
+pub mod rumqttc;
+#[cfg(test)]
+pub(crate) mod testing;
@@ -0,0 +1,81 @@
+//! [`DiscoveryTransport`] implementations for the [rumqttc](https://docs.rs/rumqttc) MQTT clients.
+//!
+//! Both the MQTT v3.1.1 [`AsyncClient`] and the MQTT v5 [`v5::AsyncClient`] are supported.
+//! MQTT v3.1.1 has no publish properties, so `content_type` and `message_expiry_interval` are ignored there.
+
+use std::future::Future;
+
+use rumqttc::v5::mqttbytes::v5::PublishProperties;
+use rumqttc::{v5, AsyncClient, ClientError};
+
+use super::{DiscoveryTransport, Message};
+use crate::mqtt::common::Qos;
+
+impl From<Qos> for rumqttc::QoS {
+    fn from(value: Qos) -> Self {
+        match value {
+            Qos::AtMostOnce => rumqttc::QoS::AtMostOnce,
+            Qos::AtLeastOnce => rumqttc::QoS::AtLeastOnce,
+            Qos::ExactlyOnce => rumqttc::QoS::ExactlyOnce,
+        }
+    }
+}
+
+impl From<Qos> for v5::mqttbytes::QoS {
+    fn from(value: Qos) -> Self {
+        match value {
+            Qos::AtMostOnce => v5::mqttbytes::QoS::AtMostOnce,
+            Qos::AtLeastOnce => v5::mqttbytes::QoS::AtLeastOnce,
+            Qos::ExactlyOnce => v5::mqttbytes::QoS::ExactlyOnce,
+        }
+    }
+}
+
+impl DiscoveryTransport for AsyncClient {
+    type Error = ClientError;
+
+    fn publish(&self, message: Message) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        AsyncClient::publish(
+            self,
+            message.topic,
+            message.qos.into(),
+            message.retain,
+            message.payload,
+        )
+    }
+
+    fn subscribe(
+        &self,
+        topic: String,
+        qos: Qos,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        AsyncClient::subscribe(self, topic, qos.into())
+    }
+}
+
+impl DiscoveryTransport for v5::AsyncClient {
+    type Error = v5::ClientError;
+
+    fn publish(&self, message: Message) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let properties = PublishProperties {
+            message_expiry_interval: message.message_expiry_interval,
+            content_type: message.content_type,
+            ..Default::default()
+        };
+        self.publish_with_properties(
+            message.topic,
+            message.qos.into(),
+            message.retain,
+            message.payload,
+            properties,
+        )
+    }
+
+    fn subscribe(
+        &self,
+        topic: String,
+        qos: Qos,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        v5::AsyncClient::subscribe(self, topic, qos.into())
+    }
+}
@@ -0,0 +1,31 @@
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use crate::mqtt::common::Qos;
+use crate::transport::{DiscoveryTransport, Message};
+
+/// A transport recording the published messages.
+#[derive(Clone, Default)]
+pub(crate) struct RecordingTransport {
+    pub messages: Arc<Mutex<Vec<Message>>>,
+}
+
+impl DiscoveryTransport for RecordingTransport {
+    type Error = Infallible;
+
+    async fn publish(&self, message: Message) -> Result<(), Self::Error> {
+        self.messages.lock().unwrap().push(message);
+        Ok(())
+    }
+
+    async fn subscribe(&self, _topic: String, _qos: Qos) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+pub(crate) fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
@@ -0,0 +1,762 @@
+//! Checks of the configuration rules Home Assistant enforces when it receives a discovery payload.
+
+use std::fmt::{Display, Formatter};
+
+use crate::device_discovery::DeviceDiscovery;
+use crate::mqtt::climate_modes::PresetMode;
+use crate::mqtt::common::{Device, SensorStateClass};
+use crate::mqtt::device_classes::SensorDeviceClass;
+use crate::mqtt::features::{AlarmFeature, VacuumFeature};
+use crate::mqtt::light::{BasicLight, ColorMode, JsonLight, Light, TemplateLight};
+use crate::mqtt::units::Unit;
+use crate::mqtt::{
+    alarm_control_panel::AlarmControlPanel, binary_sensor::BinarySensor, button::Button,
+    camera::Camera, climate::Climate, cover::Cover, device_tracker::DeviceTracker,
+    device_trigger::DeviceTrigger, event::Event, fan::Fan, humidifier::Humidifier, image::Image,
+    lawn_mower::LawnMower, lock::Lock, notify::Notify, number::Number, scene::Scene,
+    select::Select, sensor::Sensor, siren::Siren, switch::Switch, tag::Tag, text::Text,
+    update::Update, vacuum::Vacuum, valve::Valve, water_heater::WaterHeater,
+};
+use crate::Entity;
+
+/// A configuration rule broken by an entity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// The path of the field breaking the rule, such as `device.identifiers` or `options[2]`.
+    pub path: String,
+    /// The broken rule.
+    pub message: String,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Collects the broken rules while walking through a configuration.
+#[derive(Debug, Default)]
+pub struct Validator {
+    path: String,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator {
+    fn join(&self, field: &str) -> String {
+        match (
+            self.path.is_empty(),
+            field.is_empty() || field.starts_with('['),
+        ) {
+            (true, _) => field.to_string(),
+            (false, true) => format!("{}{field}", self.path),
+            (false, false) => format!("{}.{field}", self.path),
+        }
+    }
+
+    /// Reports a broken rule on a field of the configuration being checked.
+    pub fn error<S: Into<String>>(&mut self, field: &str, message: S) {
+        self.errors.push(ValidationError {
+            path: self.join(field),
+            message: message.into(),
+        });
+    }
+
+    /// Checks a nested configuration, its errors being reported under the path of the field.
+    pub fn field<V: Validate + ?Sized>(&mut self, field: &str, value: &V) {
+        let nested_path = self.join(field);
+        let path = std::mem::replace(&mut self.path, nested_path);
+        value.check(self);
+        self.path = path;
+    }
+
+    /// The reported errors, if any.
+    pub fn finish(self) -> Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+/// A configuration checked against the rules of Home Assistant before being published.
+pub trait Validate {
+    /// Reports every broken rule of the configuration to the validator.
+    fn check(&self, validator: &mut Validator);
+
+    /// Checks the configuration, returning every broken rule.
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut validator = Validator::default();
+        self.check(&mut validator);
+        validator.finish()
+    }
+}
+
+impl Validate for Device {
+    fn check(&self, validator: &mut Validator) {
+        if self.identifiers.is_empty() && self.connections.is_empty() {
+            validator.error(
+                "identifiers",
+                "device should have at least one identifier or connection",
+            );
+        }
+    }
+}
+
+/// Entities only checked against the rules shared by all entities.
+macro_rules! validate_device {
+    ($($entity:ty),* $(,)?) => {
+        $(
+            impl Validate for $entity {
+                fn check(&self, validator: &mut Validator) {
+                    validator.field("device", &self.device);
+                }
+            }
+        )*
+    };
+}
+
+validate_device!(
+    BinarySensor,
+    Button,
+    Camera,
+    DeviceTracker,
+    DeviceTrigger,
+    Image,
+    LawnMower,
+    Lock,
+    Notify,
+    Scene,
+    Siren,
+    Switch,
+    Tag,
+    Update,
+    WaterHeater,
+);
+
+fn check_range<T: PartialOrd + Display>(
+    validator: &mut Validator,
+    (min_field, min): (&str, Option<T>),
+    (max_field, max): (&str, Option<T>),
+) {
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            validator.error(
+                max_field,
+                format!("{max_field} ({max}) should not be lower than {min_field} ({min})"),
+            );
+        }
+    }
+}
+
+/// Like [`check_range`], for ranges Home Assistant requires to be non-empty.
+fn check_strict_range<T: PartialOrd + Display>(
+    validator: &mut Validator,
+    (min_field, min): (&str, Option<T>),
+    (max_field, max): (&str, Option<T>),
+) {
+    if let (Some(min), Some(max)) = (min, max) {
+        if min >= max {
+            validator.error(
+                max_field,
+                format!("{max_field} ({max}) should be greater than {min_field} ({min})"),
+            );
+        }
+    }
+}
+
+fn incompatible_unit(device_class: &impl Display, unit: &Unit) -> String {
+    format!("unit '{unit}' is not allowed with the {device_class} device_class")
+}
+
+impl Validate for Sensor {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        if self.options.is_some() {
+            if self.state_class.is_some() {
+                validator.error("options", "options are not allowed with a state_class");
+            }
+            if self.unit_of_measurement.is_some() {
+                validator.error(
+                    "options",
+                    "options are not allowed with a unit_of_measurement",
+                );
+            }
+            if !matches!(self.device_class, None | Some(SensorDeviceClass::Enum)) {
+                validator.error(
+                    "options",
+                    "options are only allowed with the enum device_class",
+                );
+            }
+        }
+        if let (Some(device_class), Some(unit)) = (&self.device_class, &self.unit_of_measurement) {
+            if !device_class.allows_unit(unit) {
+                validator.error("unit_of_measurement", incompatible_unit(device_class, unit));
+            }
+        }
+        // the state of these device classes isn't numeric
+        if let (Some(device_class), Some(_)) = (&self.device_class, &self.state_class) {
+            if matches!(
+                device_class,
+                SensorDeviceClass::Date | SensorDeviceClass::Enum | SensorDeviceClass::Timestamp
+            ) {
+                validator.error(
+                    "device_class",
+                    format!("the {device_class} device_class doesn't allow a state_class"),
+                );
+            }
+        }
+        if self.last_reset_value_template.is_some()
+            && self.state_class != Some(SensorStateClass::Total)
+        {
+            validator.error(
+                "last_reset_value_template",
+                "last_reset_value_template requires the total state_class",
+            );
+        }
+    }
+}
+
+impl Validate for Select {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        if self.options.is_empty() {
+            validator.error("options", "select should have at least one option");
+        }
+    }
+}
+
+impl Validate for Number {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        check_strict_range(validator, ("min", self.min), ("max", self.max));
+        if let (Some(device_class), Some(unit)) = (&self.device_class, &self.unit_of_measurement) {
+            if !device_class.allows_unit(unit) {
+                validator.error("unit_of_measurement", incompatible_unit(device_class, unit));
+            }
+        }
+        if self
+            .step
+            .is_some_and(|step| step.is_sign_negative() || step.is_zero())
+        {
+            validator.error("step", "step should be positive");
+        }
+    }
+}
+
+impl Validate for Text {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        check_range(validator, ("min", self.min), ("max", self.max));
+        if self.min.is_some_and(|min| min < 0) {
+            validator.error("min", "min should not be negative");
+        }
+        if self.max.is_some_and(|max| max > 255) {
+            validator.error("max", "max should not be greater than 255");
+        }
+    }
+}
+
+impl Validate for Event {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        if self.event_types.is_empty() {
+            validator.error("event_types", "event should have at least one event type");
+        }
+    }
+}
+
+/// Reports the payloads set while their feature isn't supported.
+fn check_features<F: PartialEq + Display>(
+    validator: &mut Validator,
+    supported_features: &[F],
+    payloads: &[(&str, bool, F)],
+) {
+    for (field, is_set, feature) in payloads {
+        if *is_set && !supported_features.contains(feature) {
+            validator.error(
+                field,
+                format!("{field} requires the '{feature}' supported feature"),
+            );
+        }
+    }
+}
+
+impl Validate for AlarmControlPanel {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        // every feature is supported by default
+        if let Some(supported_features) = &self.supported_features {
+            check_features(
+                validator,
+                supported_features,
+                &[
+                    (
+                        "payload_arm_home",
+                        self.payload_arm_home.is_some(),
+                        AlarmFeature::ArmHome,
+                    ),
+                    (
+                        "payload_arm_away",
+                        self.payload_arm_away.is_some(),
+                        AlarmFeature::ArmAway,
+                    ),
+                    (
+                        "payload_arm_night",
+                        self.payload_arm_night.is_some(),
+                        AlarmFeature::ArmNight,
+                    ),
+                    (
+                        "payload_arm_vacation",
+                        self.payload_arm_vacation.is_some(),
+                        AlarmFeature::ArmVacation,
+                    ),
+                    (
+                        "payload_arm_custom_bypass",
+                        self.payload_arm_custom_bypass.is_some(),
+                        AlarmFeature::ArmCustomBypass,
+                    ),
+                    (
+                        "payload_trigger",
+                        self.payload_trigger.is_some(),
+                        AlarmFeature::Trigger,
+                    ),
+                ],
+            );
+        }
+    }
+}
+
+impl Validate for Vacuum {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        let supported_features = self
+            .supported_features
+            .as_deref()
+            .unwrap_or(VacuumFeature::DEFAULT);
+        check_features(
+            validator,
+            supported_features,
+            &[
+                (
+                    "payload_start",
+                    self.payload_start.is_some(),
+                    VacuumFeature::Start,
+                ),
+                (
+                    "payload_stop",
+                    self.payload_stop.is_some(),
+                    VacuumFeature::Stop,
+                ),
+                (
+                    "payload_pause",
+                    self.payload_pause.is_some(),
+                    VacuumFeature::Pause,
+                ),
+                (
+                    "payload_return_to_base",
+                    self.payload_return_to_base.is_some(),
+                    VacuumFeature::ReturnHome,
+                ),
+                (
+                    "payload_locate",
+                    self.payload_locate.is_some(),
+                    VacuumFeature::Locate,
+                ),
+                (
+                    "payload_clean_spot",
+                    self.payload_clean_spot.is_some(),
+                    VacuumFeature::CleanSpot,
+                ),
+                (
+                    "set_fan_speed_topic",
+                    self.set_fan_speed_topic.is_some(),
+                    VacuumFeature::FanSpeed,
+                ),
+                (
+                    "fan_speed_list",
+                    self.fan_speed_list.is_some(),
+                    VacuumFeature::FanSpeed,
+                ),
+                (
+                    "send_command_topic",
+                    self.send_command_topic.is_some(),
+                    VacuumFeature::SendCommand,
+                ),
+            ],
+        );
+    }
+}
+
+impl Validate for Climate {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        check_range(
+            validator,
+            ("min_temp", self.min_temp),
+            ("max_temp", self.max_temp),
+        );
+        check_range(
+            validator,
+            ("min_humidity", self.min_humidity),
+            ("max_humidity", self.max_humidity),
+        );
+        if let Some(preset_modes) = &self.preset_modes {
+            if preset_modes.contains(&PresetMode::None) {
+                validator.error("preset_modes", "preset_modes should not include 'none'");
+            }
+        }
+    }
+}
+
+impl Validate for Fan {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        if self.speed_range_min.is_some_and(|min| min < 1) {
+            validator.error("speed_range_min", "speed_range_min should be at least 1");
+        }
+        check_range(
+            validator,
+            ("speed_range_min", self.speed_range_min),
+            ("speed_range_max", self.speed_range_max),
+        );
+    }
+}
+
+impl Validate for Humidifier {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        check_range(
+            validator,
+            ("min_humidity", self.min_humidity),
+            ("max_humidity", self.max_humidity),
+        );
+    }
+}
+
+impl Validate for Cover {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        let requires = [
+            (
+                "set_position_topic",
+                self.set_position_topic.is_some(),
+                "position_topic",
+                self.position_topic.is_some(),
+            ),
+            (
+                "position_template",
+                self.position_template.is_some(),
+                "position_topic",
+                self.position_topic.is_some(),
+            ),
+            (
+                "tilt_command_template",
+                self.tilt_command_template.is_some(),
+                "tilt_command_topic",
+                self.tilt_command_topic.is_some(),
+            ),
+            (
+                "tilt_status_template",
+                self.tilt_status_template.is_some(),
+                "tilt_status_topic",
+                self.tilt_status_topic.is_some(),
+            ),
+        ];
+        for (field, is_set, required, is_required_set) in requires {
+            if is_set && !is_required_set {
+                validator.error(field, format!("{field} requires {required}"));
+            }
+        }
+        check_range(
+            validator,
+            ("tilt_min", self.tilt_min),
+            ("tilt_max", self.tilt_max),
+        );
+    }
+}
+
+impl Validate for Valve {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        if self.reports_position == Some(true) {
+            if self.payload_open.is_some() {
+                validator.error(
+                    "payload_open",
+                    "payload_open is not allowed when the valve reports its position",
+                );
+            }
+            if self.payload_close.is_some() {
+                validator.error(
+                    "payload_close",
+                    "payload_close is not allowed when the valve reports its position",
+                );
+            }
+        }
+    }
+}
+
+impl Validate for BasicLight {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        check_range(
+            validator,
+            ("min_mireds", self.min_mireds),
+            ("max_mireds", self.max_mireds),
+        );
+    }
+}
+
+impl Validate for JsonLight {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        check_range(
+            validator,
+            ("min_mireds", self.min_mireds),
+            ("max_mireds", self.max_mireds),
+        );
+        if let Some(color_modes) = &self.supported_color_modes {
+            let exclusive = color_modes
+                .iter()
+                .any(|mode| matches!(mode, ColorMode::OnOff | ColorMode::Brightness));
+            if exclusive && color_modes.len() > 1 {
+                validator.error(
+                    "supported_color_modes",
+                    "onoff and brightness should be the only supported color mode",
+                );
+            }
+        }
+    }
+}
+
+impl Validate for TemplateLight {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        check_range(
+            validator,
+            ("min_mireds", self.min_mireds),
+            ("max_mireds", self.max_mireds),
+        );
+    }
+}
+
+impl Validate for Light {
+    fn check(&self, validator: &mut Validator) {
+        match self {
+            Light::Basic(light) => light.check(validator),
+            Light::Json(light) => light.check(validator),
+            Light::Template(light) => light.check(validator),
+        }
+    }
+}
+
+impl Validate for Entity {
+    fn check(&self, validator: &mut Validator) {
+        match self {
+            Entity::AlarmControlPanel(entity) => entity.check(validator),
+            Entity::BinarySensor(entity) => entity.check(validator),
+            Entity::Button(entity) => entity.check(validator),
+            Entity::Camera(entity) => entity.check(validator),
+            Entity::Climate(entity) => entity.check(validator),
+            Entity::Cover(entity) => entity.check(validator),
+            Entity::DeviceTracker(entity) => entity.check(validator),
+            Entity::DeviceTrigger(entity) => entity.check(validator),
+            Entity::Event(entity) => entity.check(validator),
+            Entity::Fan(entity) => entity.check(validator),
+            Entity::Humidifier(entity) => entity.check(validator),
+            Entity::Image(entity) => entity.check(validator),
+            Entity::LawnMower(entity) => entity.check(validator),
+            Entity::Light(entity) => entity.check(validator),
+            Entity::Lock(entity) => entity.check(validator),
+            Entity::Notify(entity) => entity.check(validator),
+            Entity::Number(entity) => entity.check(validator),
+            Entity::Scene(entity) => entity.check(validator),
+            Entity::Select(entity) => entity.check(validator),
+            Entity::Sensor(entity) => entity.check(validator),
+            Entity::Siren(entity) => entity.check(validator),
+            Entity::Switch(entity) => entity.check(validator),
+            Entity::Tag(entity) => entity.check(validator),
+            Entity::Text(entity) => entity.check(validator),
+            Entity::Update(entity) => entity.check(validator),
+            Entity::Vacuum(entity) => entity.check(validator),
+            Entity::Valve(entity) => entity.check(validator),
+            Entity::WaterHeater(entity) => entity.check(validator),
+        }
+    }
+}
+
+impl Validate for DeviceDiscovery {
+    fn check(&self, validator: &mut Validator) {
+        validator.field("device", &self.device);
+        for (index, component) in self.components.iter().enumerate() {
+            // the device of the components is replaced by the shared one
+            if let Err(errors) = component.validate() {
+                for error in errors
+                    .into_iter()
+                    .filter(|error| error.path != "device" && !error.path.starts_with("device."))
+                {
+                    validator.error(
+                        &format!("components[{index}].{}", error.path),
+                        error.message,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mqtt::common::Origin;
+    use crate::mqtt::number::Decimal;
+    use crate::mqtt::units::{EnergyUnit, TempUnit, TimeUnit, Unit};
+
+    fn device() -> Device {
+        Device::default().add_identifier("thermostat-01")
+    }
+
+    fn paths(result: Result<(), Vec<ValidationError>>) -> Vec<String> {
+        result
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.path)
+            .collect()
+    }
+
+    #[test]
+    fn device_should_have_an_identifier_or_a_connection() {
+        assert_eq!(
+            paths(Switch::default().validate()),
+            vec!["device.identifiers"]
+        );
+        assert_eq!(Switch::default().device(device()).validate(), Ok(()));
+    }
+
+    #[test]
+    fn sensor_options_conflict_with_measurements() {
+        let sensor = Sensor::default()
+            .device(device())
+            .options(vec!["heating", "idle"])
+            .state_class(SensorStateClass::Measurement)
+            .unit_of_measurement(Unit::Temperature(TempUnit::Celsius))
+            .last_reset_value_template("{{ value_json.last_reset }}");
+        assert_eq!(
+            paths(sensor.validate()),
+            vec!["options", "options", "last_reset_value_template"]
+        );
+    }
+
+    #[test]
+    fn unit_should_be_allowed_by_the_device_class() {
+        let sensor = Sensor::default()
+            .device(device())
+            .device_class(SensorDeviceClass::Temperature)
+            .unit_of_measurement(Unit::Energy(EnergyUnit::KiloWattHour));
+        assert_eq!(
+            sensor.validate().unwrap_err()[0].to_string(),
+            "unit_of_measurement: unit 'kWh' is not allowed with the temperature device_class"
+        );
+        assert_eq!(
+            sensor
+                .unit_of_measurement(Unit::Temperature(TempUnit::Celsius))
+                .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn timestamp_should_have_no_unit() {
+        let sensor = Sensor::default()
+            .device(device())
+            .device_class(SensorDeviceClass::Timestamp)
+            .unit_of_measurement(Unit::Time(TimeUnit::Seconds));
+        assert_eq!(
+            sensor.validate().unwrap_err()[0].to_string(),
+            "unit_of_measurement: unit 's' is not allowed with the timestamp device_class"
+        );
+    }
+
+    #[test]
+    fn select_should_have_options() {
+        assert_eq!(
+            paths(Select::default().device(device()).validate()),
+            vec!["options"]
+        );
+    }
+
+    #[test]
+    fn number_max_should_exceed_min() {
+        let number = Number::default()
+            .device(device())
+            .min(Decimal::new(10, 0))
+            .max(Decimal::new(5, 0));
+        let errors = number.validate().unwrap_err();
+        assert_eq!(
+            errors[0].to_string(),
+            "max: max (5) should be greater than min (10)"
+        );
+        let number = Number::default()
+            .device(device())
+            .min(Decimal::new(5, 0))
+            .max(Decimal::new(5, 0));
+        assert_eq!(paths(number.validate()), vec!["max"]);
+        let text = Text::default().device(device()).min(5).max(5);
+        assert_eq!(text.validate(), Ok(()));
+    }
+
+    #[test]
+    fn payloads_should_match_the_supported_features() {
+        let vacuum = Vacuum::default()
+            .device(device())
+            .payload_start("start")
+            .payload_locate("locate");
+        assert_eq!(
+            vacuum.clone().validate().unwrap_err()[0].to_string(),
+            "payload_locate: payload_locate requires the 'locate' supported feature"
+        );
+        assert_eq!(
+            vacuum
+                .supported_features(vec![VacuumFeature::Start, VacuumFeature::Locate])
+                .validate(),
+            Ok(())
+        );
+        let panel = AlarmControlPanel::default()
+            .device(device())
+            .payload_arm_night("ARM_NIGHT");
+        assert_eq!(panel.clone().validate(), Ok(()));
+        assert_eq!(
+            paths(
+                panel
+                    .supported_features(vec![AlarmFeature::ArmAway])
+                    .validate()
+            ),
+            vec!["payload_arm_night"]
+        );
+    }
+
+    #[test]
+    fn device_discovery_reports_the_path_of_its_components() {
+        let discovery = DeviceDiscovery::new("thermostat-01", device(), Origin::default())
+            .add_component(Sensor::default().unique_id("temperature"))
+            .add_component(Select::default().unique_id("mode"));
+        assert_eq!(paths(discovery.validate()), vec!["components[1].options"]);
+    }
+
+    #[test]
+    fn device_discovery_reports_the_device_class_of_its_components() {
+        let discovery = DeviceDiscovery::new("thermostat-01", device(), Origin::default())
+            .add_component(
+                Sensor::default()
+                    .unique_id("last_seen")
+                    .device_class(SensorDeviceClass::Timestamp)
+                    .state_class(SensorStateClass::Measurement),
+            );
+        assert_eq!(
+            discovery.validate().unwrap_err()[0].to_string(),
+            "components[0].device_class: the timestamp device_class doesn't allow a state_class"
+        );
+    }
+}
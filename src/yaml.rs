@@ -0,0 +1,134 @@
+//! Export of entities as [manually configured MQTT items](https://www.home-assistant.io/integrations/mqtt/#manually-configured-mqtt-items)
+//! of `configuration.yaml`, for installations where discovery is disabled.
+
+use std::collections::BTreeMap;
+
+use serde::ser::Error as _;
+use serde_json::{Map, Value};
+
+use crate::discovery::expand_base_topic;
+use crate::{Entity, Style};
+
+/// Attributes only allowed in discovery payloads.
+const DISCOVERY_ONLY_ATTRIBUTES: &[&str] = &["platform", "origin"];
+
+/// The configuration of an entity as written in `configuration.yaml`:
+/// full attribute names, `~` references expanded and discovery-only attributes removed.
+fn yaml_attributes(entity: &Entity) -> Result<Map<String, Value>, serde_json::Error> {
+    let Value::Object(mut attributes) = entity.get_attributes_with(Style::Full)? else {
+        return Err(serde_json::Error::custom(
+            "entity configuration should be an object",
+        ));
+    };
+    expand_base_topic(&mut attributes);
+    for name in DISCOVERY_ONLY_ATTRIBUTES {
+        attributes.remove(*name);
+    }
+    // a device needs an identifier or a connection, an availability list at least one topic
+    attributes.retain(|name, value| match (name.as_str(), value) {
+        ("device", Value::Object(device)) => !device.is_empty(),
+        ("availability", Value::Array(checks)) => !checks.is_empty(),
+        _ => true,
+    });
+    Ok(attributes)
+}
+
+/// Converts entities to the `mqtt:` section of `configuration.yaml`, listing them by component.
+/// Device triggers and tags can only be set up through discovery, and are rejected.
+pub fn to_yaml(entities: &[Entity]) -> Result<String, serde_yaml::Error> {
+    let mut components: BTreeMap<&str, Vec<Map<String, Value>>> = BTreeMap::new();
+    for entity in entities {
+        if matches!(entity, Entity::DeviceTrigger(_) | Entity::Tag(_)) {
+            return Err(serde_yaml::Error::custom(format!(
+                "{} is only supported through discovery",
+                entity.get_component_name()
+            )));
+        }
+        let attributes = yaml_attributes(entity).map_err(serde_yaml::Error::custom)?;
+        components
+            .entry(entity.get_component_name())
+            .or_default()
+            .push(attributes);
+    }
+    serde_yaml::to_string(&BTreeMap::from([("mqtt", components)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mqtt::common::{Availability, AvailabilityCheck, Device, Origin};
+    use crate::mqtt::device_trigger::DeviceTrigger;
+    use crate::mqtt::sensor::Sensor;
+    use crate::mqtt::switch::Switch;
+    use crate::mqtt::tag::Tag;
+
+    #[test]
+    fn can_export_entities_as_yaml() {
+        let device = Device::default().name("Garden").add_identifier("garden-01");
+        let entities: Vec<Entity> = vec![
+            Switch::default()
+                .origin(Origin::new("Integration test"))
+                .device(device.clone())
+                .topic_prefix("garden/irrigation")
+                .availability(Availability::single(AvailabilityCheck::topic(
+                    "~/availability",
+                )))
+                .command_topic("~/set")
+                .unique_id("irrigation")
+                .into(),
+            Sensor::default()
+                .device(device)
+                .state_topic("garden/soil/state")
+                .unique_id("soil_moisture")
+                .into(),
+            Sensor::default()
+                .state_topic("garden/rain/state")
+                .unique_id("rain")
+                .into(),
+        ];
+
+        assert_eq!(
+            to_yaml(&entities).unwrap(),
+            r#"mqtt:
+  sensor:
+  - availability_mode: all
+    device:
+      identifiers:
+      - garden-01
+      name: Garden
+    state_topic: garden/soil/state
+    unique_id: soil_moisture
+  - availability_mode: all
+    state_topic: garden/rain/state
+    unique_id: rain
+  switch:
+  - availability:
+    - topic: garden/irrigation/availability
+    availability_mode: all
+    command_topic: garden/irrigation/set
+    device:
+      identifiers:
+      - garden-01
+      name: Garden
+    unique_id: irrigation
+"#
+        );
+    }
+    #[test]
+    fn cannot_export_discovery_only_entities_as_yaml() {
+        let trigger: Entity = DeviceTrigger::default()
+            .topic("remote/action")
+            .r#type("button_short_press")
+            .subtype("button_1")
+            .into();
+        assert_eq!(
+            trigger.to_yaml().unwrap_err().to_string(),
+            "device_automation is only supported through discovery"
+        );
+        let tag: Entity = Tag::default().topic("reader/tag").into();
+        assert_eq!(
+            to_yaml(&[tag]).unwrap_err().to_string(),
+            "tag is only supported through discovery"
+        );
+    }
+}
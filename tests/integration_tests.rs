@@ -76,16 +76,13 @@ async fn do_with_mosquitto(callback: fn(AsyncClient) -> ()) -> (Publish, Value)
     });
     // wait for a text message
     while let Ok(message) = eventloop.poll().await {
-        match message {
-            Incoming(Packet::Publish(content)) => {
-                let payload_string =
-                    String::from_utf8(content.payload.to_vec()).expect("a valid UTF-8 string");
-                return (
-                    content,
-                    serde_json::from_str(&payload_string).expect("a valid json"),
-                );
-            }
-            _ => {}
+        if let Incoming(Packet::Publish(content)) = message {
+            let payload_string =
+                String::from_utf8(content.payload.to_vec()).expect("a valid UTF-8 string");
+            return (
+                content,
+                serde_json::from_str(&payload_string).expect("a valid json"),
+            );
         }
     }
     // or panic